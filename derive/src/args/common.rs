@@ -101,8 +101,9 @@ pub fn impl_input_object(obj: &impl CommonObject) -> darling::Result<TokenStream
     let object_ident = obj.get_ident();
     let name = get_type_name(obj)?;
     let crate_name = get_crate_name();
+    let (impl_generics, ty_generics, where_clause) = obj.get_generics()?.split_for_impl();
     let type_name = obj.should_impl_type_name().then_some(quote! {
-        impl #crate_name::internal::TypeName for #object_ident {
+        impl #impl_generics #crate_name::internal::TypeName for #object_ident #ty_generics #where_clause {
             fn get_type_name() -> std::borrow::Cow<'static, str> {
                 #name.into()
             }
@@ -110,8 +111,97 @@ pub fn impl_input_object(obj: &impl CommonObject) -> darling::Result<TokenStream
     });
     Ok(quote! {
         #type_name
-        impl #crate_name::internal::InputTypeName for #object_ident {}
-        impl #crate_name::internal::InputObject for #object_ident {}
+        impl #impl_generics #crate_name::internal::InputTypeName for #object_ident #ty_generics #where_clause {}
+        impl #impl_generics #crate_name::internal::InputObject for #object_ident #ty_generics #where_clause {}
+    })
+}
+
+pub fn impl_flatten_args<O, F>(obj: &O) -> darling::Result<TokenStream>
+where
+    O: CommonObject + GetFields<F>,
+    F: CommonField + GetArgs<()>,
+{
+    let object_ident = obj.get_ident();
+    let crate_name = get_crate_name();
+    let (impl_generics, ty_generics, where_clause) = obj.get_generics()?.split_for_impl();
+    let fields = obj.get_fields()?;
+
+    let mut arg_names = Vec::new();
+    let mut register_flatten_types = Vec::new();
+    let mut define_args = Vec::new();
+    let mut args_value = Vec::new();
+    let mut args_usage = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let field_ident = field.get_ident()?;
+        let item = syn::Ident::new(&format!("arg{}", index), field_ident.span());
+        args_usage.push(quote!(#field_ident: #item,));
+        if field.get_skip() {
+            args_value.push(quote! {
+                let #item = Default::default();
+            });
+            continue;
+        }
+        let ty = get_owned_type(field.get_type()?);
+        if field.get_flatten() {
+            arg_names.push(quote! {
+                names.extend(<#ty as #crate_name::internal::FlattenArgs>::get_flatten_arg_names());
+            });
+            let static_ty = replace_type_generics_with_static(ty);
+            register_flatten_types.push(quote! {
+                let registry = <#static_ty as #crate_name::internal::FlattenArgs>::register_args_types(registry);
+            });
+            define_args.push(quote! {
+                let field = <#ty as #crate_name::internal::FlattenArgs>::define_args(field);
+            });
+            args_value.push(quote! {
+                let #item = <#ty as #crate_name::internal::FlattenArgs>::from_args(__args)?;
+            });
+            continue;
+        }
+        let arg_name = get_input_field_name(field)?;
+        let description = field.get_doc()?.map(|doc| {
+            quote! {
+                let arg = arg.description(#doc);
+            }
+        });
+        arg_names.push(quote! {
+            names.push(#arg_name);
+        });
+        define_args.push(quote! {
+            let arg = #crate_name::dynamic::InputValue::new(#arg_name, <#ty as #crate_name::internal::GetInputTypeRef>::get_input_type_ref());
+            #description
+            let field = field.argument(arg);
+        });
+        args_value.push(quote! {
+            let #item = #crate_name::internal::FromValue::from_value(__args.try_get(#arg_name)).map_err(|e| e.into_arg_error(#arg_name))?;
+        });
+    }
+    let register_nested_types = get_nested_type_register_code(obj)?;
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::FlattenArgs for #object_ident #ty_generics #where_clause {
+            fn get_flatten_arg_names() -> Vec<&'static str> {
+                let mut names = Vec::new();
+                #(#arg_names)*
+                names
+            }
+            fn register_args_types(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #register_nested_types
+                #(#register_flatten_types)*
+                registry
+            }
+            fn define_args(field: #crate_name::dynamic::Field) -> #crate_name::dynamic::Field {
+                #(#define_args)*
+                field
+            }
+            fn from_args(__args: &#crate_name::dynamic::ObjectAccessor<'_>) -> #crate_name::Result<Self> {
+                #(#args_value)*
+                Ok(Self {
+                    #(#args_usage)*
+                })
+            }
+        }
     })
 }
 
@@ -292,11 +382,14 @@ where
 {
     let mut errors = Vec::new();
     let mut types = Vec::new();
+    let mut map_types = Vec::new();
+    let mut result_unions = Vec::new();
+    let mut flatten_args = Vec::new();
 
     let fields = object.get_fields()?;
     fields
         .iter()
-        .filter(|field| !field.get_skip())
+        .filter(|field| !field.get_skip() && !field.get_flatten())
         .for_each(|field| {
             let args = match field.get_args() {
                 Ok(args) => args,
@@ -307,7 +400,14 @@ where
             };
             args.iter().for_each(|arg| {
                 if let BaseFnArg::Typed(ty) = arg.get_arg() {
-                    if is_arg_ctx(arg) {
+                    if is_arg_injected(arg) {
+                        return;
+                    }
+                    if arg.get_flatten() {
+                        let ty = get_owned_type(&ty.ty);
+                        if !flatten_args.contains(&ty) {
+                            flatten_args.push(ty);
+                        }
                         return;
                     }
                     if !types.contains(&&ty.ty) {
@@ -315,14 +415,25 @@ where
                     }
                 }
             });
+            if field.get_errors_as_data() {
+                match (
+                    get_errors_as_data_types(field),
+                    get_result_union_name(field),
+                ) {
+                    (Ok((ok_ty, err_ty)), Ok(name)) => result_unions.push((ok_ty, err_ty, name)),
+                    (Err(err), _) | (_, Err(err)) => errors.push(err),
+                }
+                return;
+            }
             let ty = field.get_type();
-            match ty {
-                Ok(ty) => {
+            match (ty, field.get_map_as()) {
+                (Ok(ty), Some(map_as)) => map_types.push((get_owned_type(ty), map_as.get_repr())),
+                (Ok(ty), None) => {
                     if !types.contains(&ty) {
                         types.push(ty);
                     }
                 }
-                Err(err) => errors.push(err),
+                (Err(err), _) => errors.push(err),
             };
         });
 
@@ -339,9 +450,41 @@ where
             }
         })
         .collect::<Vec<_>>();
+    let crate_name = get_crate_name();
+    let map_codes = map_types
+        .into_iter()
+        .map(|(ty, repr)| {
+            let ty = replace_type_generics_with_static(ty);
+            quote! {
+                let registry = registry.register::<<#ty as #crate_name::internal::IntoMapOutput<#repr>>::Output>();
+            }
+        })
+        .collect::<Vec<_>>();
+    let result_union_codes = result_unions
+        .into_iter()
+        .map(|(ok_ty, err_ty, name)| {
+            let ok_ty = replace_type_generics_with_static(ok_ty);
+            let err_ty = replace_type_generics_with_static(err_ty);
+            quote! {
+                let registry = #crate_name::internal::register_result_union::<#ok_ty, #err_ty>(registry, #name);
+            }
+        })
+        .collect::<Vec<_>>();
+    let flatten_args_codes = flatten_args
+        .into_iter()
+        .map(|ty| {
+            let ty = replace_type_generics_with_static(ty);
+            quote! {
+                let registry = <#ty as #crate_name::internal::FlattenArgs>::register_args_types(registry);
+            }
+        })
+        .collect::<Vec<_>>();
 
     Ok(quote! {
         #(#errors)*
         #(#codes)*
+        #(#map_codes)*
+        #(#result_union_codes)*
+        #(#flatten_args_codes)*
     })
 }
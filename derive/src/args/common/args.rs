@@ -1,10 +1,12 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::args::common::get_field_name;
 use crate::utils::common::CommonArg;
+use crate::utils::common::CommonField;
+use crate::utils::common::GetArgs;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::impl_block::BaseFnArg;
-use crate::utils::impl_block::TypedArg;
 use crate::utils::rename_rule::calc_arg_name;
 use crate::utils::type_utils::get_owned_type;
 use crate::utils::type_utils::get_value_type;
@@ -16,7 +18,19 @@ pub fn get_arg_ident(arg: &impl CommonArg) -> syn::Ident {
 
 pub fn is_arg_ctx(arg: &impl CommonArg) -> bool {
     arg.is_marked_as_ctx()
-        || matches!(arg.get_arg(), BaseFnArg::Typed(TypedArg{ident, ..}) if ident == "ctx" || ident == "_ctx")
+        || matches!(arg.get_arg(), BaseFnArg::Typed(typed) if typed.ident == "ctx" || typed.ident == "_ctx")
+}
+
+pub fn is_arg_data(arg: &impl CommonArg) -> bool {
+    arg.is_marked_as_data()
+}
+
+/// arguments filled by the resolver instead of the GraphQL arguments
+pub fn is_arg_injected(arg: &impl CommonArg) -> bool {
+    is_arg_ctx(arg)
+        || arg.is_marked_as_data()
+        || arg.is_marked_as_lookahead()
+        || arg.is_marked_as_selection()
 }
 
 pub fn get_self_arg_usage(arg: &impl CommonArg) -> darling::Result<TokenStream> {
@@ -29,7 +43,7 @@ pub fn get_typed_arg_usage(arg: &impl CommonArg) -> darling::Result<TokenStream>
     let BaseFnArg::Typed(typed) = arg.get_arg() else {
         unreachable!("Expected typed argument");
     };
-    let is_ctx = is_arg_ctx(arg);
+    let is_ctx = is_arg_ctx(arg) || is_arg_data(arg);
     let is_owned = !is_type_ref(&typed.ty);
     if is_ctx || is_owned {
         Ok(quote!(#arg_ident,))
@@ -39,19 +53,24 @@ pub fn get_typed_arg_usage(arg: &impl CommonArg) -> darling::Result<TokenStream>
 }
 
 pub fn get_argument_definition(arg: &impl CommonArg) -> TokenStream {
-    if is_arg_ctx(arg) {
+    if is_arg_injected(arg) {
         return quote!();
     }
     let BaseFnArg::Typed(typed) = arg.get_arg() else {
         return quote!();
     };
     let crate_name = get_crate_name();
+    let arg_type = get_owned_type(&typed.ty);
+    if arg.get_flatten() {
+        return quote! {
+            let field = <#arg_type as #crate_name::internal::FlattenArgs>::define_args(field);
+        };
+    }
     let arg_name = calc_arg_name(
         arg.get_name(),
         &typed.ident.to_string(),
         arg.get_arg_rename_rule(),
     );
-    let arg_type = get_owned_type(&typed.ty);
 
     quote! {
         let arg = #crate_name::dynamic::InputValue::new(#arg_name, <#arg_type as #crate_name::internal::GetInputTypeRef>::get_input_type_ref());
@@ -63,6 +82,48 @@ pub fn get_argument_definitions(args: &[impl CommonArg]) -> darling::Result<Toke
     Ok(args.iter().map(get_argument_definition).collect())
 }
 
+pub fn get_check_flatten_args_code<F, A>(field: &F) -> darling::Result<TokenStream>
+where
+    F: CommonField + GetArgs<A>,
+    A: CommonArg,
+{
+    let args = field.get_args()?;
+    if !args.iter().any(|arg| arg.get_flatten()) {
+        return Ok(quote!());
+    }
+    let crate_name = get_crate_name();
+    let field_name = get_field_name(field)?;
+    let names = args
+        .iter()
+        .filter(|arg| !is_arg_injected(*arg))
+        .filter_map(|arg| match arg.get_arg() {
+            BaseFnArg::Typed(typed) => Some((arg, typed)),
+            BaseFnArg::Receiver(_) => None,
+        })
+        .map(|(arg, typed)| {
+            if arg.get_flatten() {
+                let arg_type = get_owned_type(&typed.ty);
+                quote! {
+                    names.extend(<#arg_type as #crate_name::internal::FlattenArgs>::get_flatten_arg_names());
+                }
+            } else {
+                let arg_name = calc_arg_name(
+                    arg.get_name(),
+                    &typed.ident.to_string(),
+                    arg.get_arg_rename_rule(),
+                );
+                quote! {
+                    names.push(#arg_name);
+                }
+            }
+        });
+    Ok(quote! {
+        let mut names = Vec::new();
+        #(#names)*
+        #crate_name::internal::check_flatten_arg_names(#field_name, names);
+    })
+}
+
 pub fn get_typed_arg_definition(arg: &impl CommonArg) -> darling::Result<TokenStream> {
     let BaseFnArg::Typed(typed) = arg.get_arg() else {
         unreachable!("Expected typed argument");
@@ -73,19 +134,43 @@ pub fn get_typed_arg_definition(arg: &impl CommonArg) -> darling::Result<TokenSt
         Ok(quote! {
             let #arg_ident = &ctx;
         })
+    } else if is_arg_data(arg) {
+        if !is_type_ref(&typed.ty) {
+            return Err(
+                darling::Error::custom("data arguments must be references").with_span(&typed.ty)
+            );
+        }
+        let data_type = get_owned_type(&typed.ty);
+        Ok(quote! {
+            let #arg_ident = #crate_name::internal::get_context_data::<#data_type>(&ctx)?;
+        })
+    } else if arg.is_marked_as_lookahead() {
+        Ok(quote! {
+            let #arg_ident = ctx.look_ahead();
+        })
+    } else if arg.is_marked_as_selection() {
+        Ok(quote! {
+            let #arg_ident = #crate_name::SelectedFields::from_context(&ctx);
+        })
     } else {
         let arg_name = calc_arg_name(
             arg.get_name(),
             &typed.ident.to_string(),
             arg.get_arg_rename_rule(),
         );
+        if arg.get_flatten() {
+            let arg_type = get_owned_type(&typed.ty);
+            return Ok(quote! {
+                let #arg_ident = <#arg_type as #crate_name::internal::FlattenArgs>::from_args(&ctx.args)?;
+            });
+        }
         let value_type = get_value_type(&typed.ty);
         match value_type {
             None => Ok(quote! {
-                let #arg_ident = #crate_name::internal::FromValue::from_value(ctx.args.try_get(#arg_name)).map_err(|e| e.into_arg_error(#arg_name))?;
+                let #arg_ident = #crate_name::internal::FromValue::from_arg_value(&ctx, ctx.args.try_get(#arg_name)).map_err(|e| e.into_arg_error(#arg_name))?;
             }),
             Some(ty) => Ok(quote! {
-                let #arg_ident: #ty = #crate_name::internal::FromValue::from_value(ctx.args.try_get(#arg_name)).map_err(|e| e.into_arg_error(#arg_name))?;
+                let #arg_ident: #ty = #crate_name::internal::FromValue::from_arg_value(&ctx, ctx.args.try_get(#arg_name)).map_err(|e| e.into_arg_error(#arg_name))?;
             }),
         }
     }
@@ -8,8 +8,10 @@ use crate::utils::common::GetArgs;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::deprecation::Deprecation;
 use crate::utils::error::IntoTokenStream;
+use crate::utils::rename_rule::RenameTarget;
 use crate::utils::rename_rule::calc_field_name;
 use crate::utils::type_utils::get_owned_type;
+use crate::utils::type_utils::get_result_types;
 
 pub fn get_field_type(field: &impl CommonField) -> darling::Result<&syn::Type> {
     let ty = field.get_type()?;
@@ -17,6 +19,63 @@ pub fn get_field_type(field: &impl CommonField) -> darling::Result<&syn::Type> {
     Ok(owned_type)
 }
 
+pub fn get_field_output_type(field: &impl CommonField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let field_type = get_field_type(field)?;
+    match field.get_map_as() {
+        Some(map_as) => {
+            let repr = map_as.get_repr();
+            Ok(quote!(<#field_type as #crate_name::internal::IntoMapOutput<#repr>>::Output))
+        }
+        None => Ok(quote!(#field_type)),
+    }
+}
+
+/// The `T` and `E` of a `#[graphql(errors_as_data)]` field returning `Result<T, E>`
+pub fn get_errors_as_data_types(
+    field: &impl CommonField,
+) -> darling::Result<(&syn::Type, &syn::Type)> {
+    let ty = get_field_type(field)?;
+    get_result_types(ty).ok_or_else(|| {
+        darling::Error::custom("errors_as_data requires a `Result<T, E>` return type").with_span(ty)
+    })
+}
+
+/// The name of the union generated for a `#[graphql(errors_as_data)]` field
+pub fn get_result_union_name(field: &impl CommonField) -> darling::Result<String> {
+    if let Some(name) = field.get_result_union_name() {
+        return Ok(name.to_string());
+    }
+    let field_name = get_field_name(field)?;
+    Ok(format!("{}Result", RenameTarget::Type.rename(&field_name)))
+}
+
+pub fn get_field_type_ref_code(field: &impl CommonField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    if field.get_errors_as_data() {
+        let union_name = get_result_union_name(field)?;
+        return Ok(quote!(#crate_name::dynamic::TypeRef::named_nn(#union_name)));
+    }
+    let field_type = get_field_output_type(field)?;
+    Ok(quote!(<#field_type as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref()))
+}
+
+pub fn get_map_value_code(field: &impl CommonField) -> TokenStream {
+    let crate_name = get_crate_name();
+    match field.get_map_as() {
+        Some(map_as) => {
+            let repr = map_as.get_repr();
+            quote! {
+                let value = #crate_name::internal::IntoMapOutput::<#repr>::into_map_output(value);
+            }
+        }
+        None if field.get_errors_as_data() => quote! {
+            let value = #crate_name::internal::ResultUnion::from(value);
+        },
+        None => quote!(),
+    }
+}
+
 pub fn get_args_definition<F, A>(field: &F) -> darling::Result<TokenStream>
 where
     F: GetArgs<A>,
@@ -85,15 +144,17 @@ where
     let crate_name = get_crate_name();
 
     let field_name = get_field_name(method)?;
-    let field_type = get_field_type(method)?;
+    let field_type_ref = get_field_type_ref_code(method)?;
     let graphql_args_definition = get_args_definition(method)?;
     let execute = method.get_execute_code()?;
+    let map_value = get_map_value_code(method);
     let resolve = method.get_resolve_code()?;
     Ok(quote! {
-        let field = #crate_name::dynamic::Field::new(#field_name, <#field_type as #crate_name::internal::GetOutputTypeRef>::get_output_type_ref(), |ctx| {
+        let field = #crate_name::dynamic::Field::new(#field_name, #field_type_ref, |ctx| {
             #crate_name::dynamic::FieldFuture::new(async move {
                 #graphql_args_definition
                 #execute
+                #map_value
                 #resolve
             })
         });
@@ -10,6 +10,7 @@ use crate::utils::interface_attr::InterfaceImplAttr;
 pub fn get_register_interface_code(
     obj: &impl CommonInterfaceAttrs,
 ) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
     let mut paths = Vec::new();
     obj.get_marks().iter().for_each(|mark| {
         paths.push(mark.path.clone());
@@ -25,8 +26,21 @@ pub fn get_register_interface_code(
             }
         })
         .collect();
+    let marks: Vec<_> = obj
+        .get_marks()
+        .iter()
+        .map(|mark| {
+            let path = &mark.path;
+            quote! {
+                let registry = registry.add_interface_mark::<dyn #path>(
+                    <Self as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                );
+            }
+        })
+        .collect();
     Ok(quote! {
         #(#codes)*
+        #(#marks)*
     })
 }
 
@@ -0,0 +1,75 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::quote;
+
+use crate::args::ResolvedObjectFields;
+use crate::args::common;
+use crate::args::resolved_object_fields::ResolvedObjectFieldsMethodContext;
+use crate::utils::common::CommonField;
+use crate::utils::common::CommonObject;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::impl_block::FromItemImpl;
+use crate::utils::type_utils::get_type_path;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::SetContext;
+
+pub struct ComplexObjectFields(ResolvedObjectFields);
+
+impl FromItemImpl for ComplexObjectFields {
+    fn from_item_impl(item: &mut syn::ItemImpl) -> darling::Result<Self> {
+        let mut fields = ResolvedObjectFields::from_item_impl(item)?;
+        let ctx = ResolvedObjectFieldsMethodContext {
+            complex: true,
+            ..fields.make_context()
+        };
+        fields.0.set_context(ctx);
+        Ok(Self(fields))
+    }
+}
+
+fn impl_complex_object(object: &ResolvedObjectFields) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ty = get_type_path(&object.ty)?;
+    let field_names = object
+        .methods
+        .iter()
+        .filter(|method| !method.get_skip())
+        .map(|method| {
+            let name = common::get_field_name(method)?;
+            Ok(quote! {
+                names.push(#name);
+            })
+        })
+        .collect::<darling::Result<TokenStream>>()?;
+    let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
+    let define_fields = common::get_define_fields_code(object)?;
+    let (impl_generics, _, where_clause) = object.get_generics()?.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::ComplexObject for #ty #where_clause {
+            fn get_complex_field_names() -> Vec<&'static str> {
+                let mut names = Vec::new();
+                #field_names
+                names
+            }
+            fn register_complex_types(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #register_nested_types
+                registry
+            }
+            fn define_complex_fields<__P: #crate_name::internal::FlattenPath<Self>>(object: #crate_name::dynamic::Object) -> #crate_name::dynamic::Object {
+                #define_fields
+                object
+            }
+        }
+    })
+}
+
+impl ToTokens for ComplexObjectFields {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_complex_object = impl_complex_object(&self.0).into_token_stream();
+        tokens.extend(quote! {
+            #impl_complex_object
+        });
+    }
+}
@@ -0,0 +1,147 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::quote;
+use syn::Generics;
+use syn::Path;
+
+use crate::args::input_object::InputObjectField;
+use crate::args::input_object::InputObjectFieldContext;
+use crate::args::input_object::impl_flatten_input_object;
+use crate::utils::common::CommonObject;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseStruct;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::path_attr::PathAttr;
+use crate::utils::register_attr::RegisterAttr;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct ExpandInputObjectAttrs {
+    pub target: PathAttr,
+
+    #[darling(default)]
+    pub rename_fields: Option<RenameRule>,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "register")]
+    pub registers: Vec<RegisterAttr>,
+}
+
+from_derive_input!(
+    ExpandInputObject,
+    WithAttributes<ExpandInputObjectAttrs, BaseStruct<InputObjectField, Generics>>,
+    ctx,
+);
+
+impl MakeContext<InputObjectFieldContext> for ExpandInputObject {
+    fn make_context(&self) -> InputObjectFieldContext {
+        InputObjectFieldContext {
+            rename_fields: self.attrs.rename_fields,
+        }
+    }
+}
+
+impl CommonObject for ExpandInputObject {
+    fn get_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn should_impl_type_name(&self) -> bool {
+        false
+    }
+
+    fn get_ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    fn get_type(&self) -> darling::Result<Path> {
+        Ok(self.ident.clone().into())
+    }
+
+    fn get_generics(&self) -> darling::Result<&Generics> {
+        Ok(&self.generics)
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+impl GetFields<InputObjectField> for ExpandInputObject {
+    fn get_fields(&self) -> darling::Result<&Vec<InputObjectField>> {
+        Ok(&self.data.fields)
+    }
+}
+
+fn check_generics(expand: &ExpandInputObject) -> darling::Result<()> {
+    if expand.generics.params.is_empty() {
+        return Ok(());
+    }
+    Err(
+        darling::Error::custom("ExpandInputObject does not support generics")
+            .with_span(&expand.generics),
+    )
+}
+
+fn impl_expand_input_object(expand: &ExpandInputObject) -> darling::Result<TokenStream> {
+    check_generics(expand)?;
+    let crate_name = get_crate_name();
+    let ident = &expand.ident;
+    let target = &expand.attrs.target.0;
+    let name = ident.to_string();
+
+    Ok(quote! {
+        impl #crate_name::internal::ExpandInputObject for #ident {
+            type Target = #target;
+            fn get_expand_input_object_name() -> std::borrow::Cow<'static, str> {
+                #name.into()
+            }
+        }
+    })
+}
+
+fn impl_register(expand: &ExpandInputObject) -> darling::Result<TokenStream> {
+    check_generics(expand)?;
+    let crate_name = get_crate_name();
+    let ident = &expand.ident;
+    let register_attr = &expand.attrs.registers;
+
+    Ok(quote! {
+        impl #crate_name::internal::Register for #ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+
+                #( #register_attr )*
+
+                let mut registry = <Self as #crate_name::internal::FlattenInputObject>::register_flatten_types(registry);
+
+                registry.data.get_mut_or_default::<#crate_name::internal::InputExpansions>().add::<Self>();
+
+                registry.update_input_object(
+                    <<Self as #crate_name::internal::ExpandInputObject>::Target as #crate_name::internal::InputObject>::get_input_object_type_name().as_ref(),
+                    <Self as #crate_name::internal::ExpandInputObject>::get_expand_input_object_name().as_ref(),
+                    <Self as #crate_name::internal::FlattenInputObject>::get_flatten_field_names(),
+                    <Self as #crate_name::internal::FlattenInputObject>::flatten_fields,
+                )
+            }
+        }
+    })
+}
+
+impl ToTokens for ExpandInputObject {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_expand_input_object = impl_expand_input_object(self).into_token_stream();
+        let impl_flatten_input_object = impl_flatten_input_object(self).into_token_stream();
+        let impl_register = impl_register(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_expand_input_object
+            #impl_flatten_input_object
+            #impl_register
+        });
+    }
+}
@@ -0,0 +1,187 @@
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::quote;
+
+use crate::args::ExpandObjectFields;
+use crate::args::common;
+use crate::args::common::FieldImplementor;
+use crate::args::expand_object_fields::ExpandObjectFieldsArg;
+use crate::args::expand_object_fields::ExpandObjectFieldsMethod;
+use crate::args::expand_object_fields::ExpandObjectFieldsMethodContext;
+use crate::utils::common::CommonField;
+use crate::utils::common::GetArgs;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::deprecation::Deprecation;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::impl_block::FromItemImpl;
+use crate::utils::map_as::MapAs;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::type_utils::get_type_path;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::SetContext;
+
+pub struct ExpandInterfaceFields(ExpandObjectFields);
+
+impl FromItemImpl for ExpandInterfaceFields {
+    fn from_item_impl(item: &mut syn::ItemImpl) -> darling::Result<Self> {
+        let mut fields = ExpandObjectFields::from_item_impl(item)?;
+        let ctx = ExpandObjectFieldsMethodContext {
+            interface: true,
+            ..fields.make_context()
+        };
+        fields.0.set_context(ctx);
+        Ok(Self(fields))
+    }
+}
+
+/// The same method, defined as a field of the interface itself
+struct InterfaceMethod<'a>(&'a ExpandObjectFieldsMethod);
+
+impl GetArgs<ExpandObjectFieldsArg> for InterfaceMethod<'_> {
+    fn get_args(&self) -> darling::Result<&Vec<ExpandObjectFieldsArg>> {
+        self.0.get_args()
+    }
+}
+
+impl CommonField for InterfaceMethod<'_> {
+    fn get_name(&self) -> Option<&str> {
+        self.0.get_name()
+    }
+
+    fn get_ident(&self) -> darling::Result<&Ident> {
+        self.0.get_ident()
+    }
+
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        self.0.get_type()
+    }
+
+    fn get_skip(&self) -> bool {
+        self.0.get_skip()
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        self.0.get_doc()
+    }
+
+    fn get_deprecation(&self) -> darling::Result<Deprecation> {
+        self.0.get_deprecation()
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.0.get_field_rename_rule()
+    }
+
+    fn get_map_as(&self) -> Option<&MapAs> {
+        self.0.get_map_as()
+    }
+
+    fn get_errors_as_data(&self) -> bool {
+        self.0.get_errors_as_data()
+    }
+    fn get_result_union_name(&self) -> Option<&str> {
+        self.0.get_result_union_name()
+    }
+}
+
+impl FieldImplementor for InterfaceMethod<'_> {
+    fn define_field(&self) -> darling::Result<TokenStream> {
+        let crate_name = get_crate_name();
+        let field_name = common::get_field_name(self)?;
+        let field_type_ref = common::get_field_type_ref_code(self)?;
+        Ok(quote! {
+            let field = #crate_name::dynamic::InterfaceField::new(#field_name, #field_type_ref);
+        })
+    }
+
+    fn get_execute_code(&self) -> darling::Result<TokenStream> {
+        unreachable!("Interface method can't be executed")
+    }
+
+    fn get_resolve_code(&self) -> darling::Result<TokenStream> {
+        unreachable!("Interface method can't be resolved")
+    }
+
+    fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
+        common::get_argument_definitions(self.get_args()?)
+    }
+
+    fn get_field_description_code(&self) -> darling::Result<TokenStream> {
+        common::field_description(self)
+    }
+
+    fn get_field_deprecation_code(&self) -> darling::Result<TokenStream> {
+        common::field_deprecation_code(self)
+    }
+
+    fn get_field_usage_code(&self) -> darling::Result<TokenStream> {
+        Ok(quote! {
+            let object = object.field(field);
+        })
+    }
+}
+
+fn define_interface_fields_code(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
+    Ok(expand
+        .get_fields()?
+        .iter()
+        .filter(|method| !method.get_skip())
+        .map(|method| common::build_field(&InterfaceMethod(method)).into_token_stream())
+        .collect())
+}
+
+fn define_object_fields_code(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
+    Ok(expand
+        .get_fields()?
+        .iter()
+        .filter(|method| !method.get_skip())
+        .map(|method| common::build_field(method).into_token_stream())
+        .collect())
+}
+
+fn impl_register(expand: &ExpandObjectFields) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let (impl_generics, _, where_clause) = expand.generics.split_for_impl();
+    let ty = get_type_path(&expand.ty)?;
+
+    let register_nested_types = common::get_nested_type_register_code(expand).into_token_stream();
+
+    let define_interface_fields = define_interface_fields_code(expand).into_token_stream();
+
+    let define_object_fields = define_object_fields_code(expand).into_token_stream();
+
+    let register_fns = common::call_register_fns();
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::Register for #ty #where_clause {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+
+                #register_nested_types
+
+                #register_fns
+
+                registry.update_interface::<<Self as #crate_name::internal::ExpandInterface>::Interface, _, _>(
+                    <Self as #crate_name::internal::ExpandInterface>::get_expand_interface_name().as_ref(),
+                    |object| {
+                        #define_interface_fields
+                        object
+                    },
+                    |object, #[allow(unused_variables)] cast| {
+                        #define_object_fields
+                        object
+                    },
+                )
+            }
+        }
+    })
+}
+
+impl ToTokens for ExpandInterfaceFields {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_register = impl_register(&self.0).into_token_stream();
+        tokens.extend(quote! {
+            #impl_register
+        });
+    }
+}
@@ -22,6 +22,7 @@ use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::BaseItemImpl;
 use crate::utils::impl_block::BaseMethod;
 use crate::utils::macros::*;
+use crate::utils::map_as::MapAs;
 use crate::utils::rename_rule::RenameRule;
 use crate::utils::type_utils::get_type_path;
 use crate::utils::type_utils::remove_path_generics;
@@ -39,6 +40,18 @@ pub struct ExpandObjectFieldsArgAttrs {
 
     #[darling(default)]
     pub ctx: bool,
+
+    #[darling(default)]
+    pub data: bool,
+
+    #[darling(default)]
+    pub lookahead: bool,
+
+    #[darling(default)]
+    pub selection: bool,
+
+    #[darling(default)]
+    pub flatten: bool,
 }
 
 impl Attributes for ExpandObjectFieldsArgAttrs {
@@ -48,6 +61,8 @@ impl Attributes for ExpandObjectFieldsArgAttrs {
 #[derive(Default, Debug, Clone)]
 pub struct ExpandObjectFieldsArgContext {
     pub rename_args: Option<RenameRule>,
+    /// the parent is an object implementing the expanded interface, reached through `cast`
+    pub interface: bool,
 }
 
 from_fn_arg!(ExpandObjectFieldsArg,
@@ -71,6 +86,15 @@ pub struct ExpandObjectFieldsMethodAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub map_as: Option<MapAs>,
+
+    #[darling(default)]
+    pub errors_as_data: bool,
+
+    #[darling(default)]
+    pub result_union_name: Option<String>,
 }
 
 impl Attributes for ExpandObjectFieldsMethodAttrs {
@@ -82,6 +106,8 @@ pub struct ExpandObjectFieldsMethodContext {
     pub rename_args: Option<RenameRule>,
     pub rename_fields: Option<RenameRule>,
     pub expand_ty: Option<syn::Type>,
+    /// the fields are defined on every object implementing the expanded interface
+    pub interface: bool,
 }
 
 from_impl_item_method!(
@@ -97,6 +123,7 @@ impl MakeContext<ExpandObjectFieldsArgContext> for ExpandObjectFieldsMethod {
     fn make_context(&self) -> ExpandObjectFieldsArgContext {
         ExpandObjectFieldsArgContext {
             rename_args: self.attrs.rename_args.or(self.ctx.rename_args),
+            interface: self.ctx.interface,
         }
     }
 }
@@ -130,6 +157,7 @@ impl MakeContext<ExpandObjectFieldsMethodContext> for ExpandObjectFields {
             rename_args: self.attrs.rename_args,
             rename_fields: self.attrs.rename_fields,
             expand_ty: Some(self.ty.clone()),
+            interface: false,
         }
     }
 }
@@ -188,11 +216,14 @@ impl CommonField for ExpandObjectFieldsMethod {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
-    fn get_args_rename_rule(&self) -> Option<&RenameRule> {
-        self.attrs
-            .rename_args
-            .as_ref()
-            .or(self.ctx.rename_args.as_ref())
+    fn get_map_as(&self) -> Option<&MapAs> {
+        self.attrs.map_as.as_ref()
+    }
+    fn get_errors_as_data(&self) -> bool {
+        self.attrs.errors_as_data
+    }
+    fn get_result_union_name(&self) -> Option<&str> {
+        self.attrs.result_union_name.as_deref()
     }
 }
 
@@ -216,6 +247,22 @@ impl CommonArg for ExpandObjectFieldsArg {
     fn is_marked_as_ctx(&self) -> bool {
         self.attrs.ctx
     }
+
+    fn is_marked_as_data(&self) -> bool {
+        self.attrs.data
+    }
+
+    fn is_marked_as_lookahead(&self) -> bool {
+        self.attrs.lookahead
+    }
+
+    fn is_marked_as_selection(&self) -> bool {
+        self.attrs.selection
+    }
+
+    fn get_flatten(&self) -> bool {
+        self.attrs.flatten
+    }
 }
 
 impl CommonMethod for ExpandObjectFieldsMethod {
@@ -240,6 +287,12 @@ impl ArgImplementor for ExpandObjectFieldsArg {
     fn get_self_arg_definition(&self) -> darling::Result<TokenStream> {
         let crate_name = get_crate_name();
         let arg_ident = common::get_arg_ident(self);
+        if self.ctx.interface {
+            return Ok(quote! {
+                let parent = cast(ctx.parent_value)?.into();
+                let #arg_ident = &parent;
+            });
+        }
         Ok(quote! {
             let parent = ctx.parent_value.try_downcast_ref::<<Self as #crate_name::internal::ParentType>::Type>()?.into();
             let #arg_ident = &parent;
@@ -261,7 +314,28 @@ impl ArgImplementor for ExpandObjectFieldsArg {
 
 impl FieldImplementor for ExpandObjectFieldsMethod {
     fn define_field(&self) -> darling::Result<TokenStream> {
-        common::define_field(self)
+        if !self.ctx.interface {
+            return common::define_field(self);
+        }
+        let crate_name = get_crate_name();
+
+        let field_name = common::get_field_name(self)?;
+        let field_type_ref = common::get_field_type_ref_code(self)?;
+        let graphql_args_definition = common::get_args_definition(self)?;
+        let execute = self.get_execute_code()?;
+        let map_value = common::get_map_value_code(self);
+        let resolve = self.get_resolve_code()?;
+        // the resolver moves the cast of the implementing object in
+        Ok(quote! {
+            let field = #crate_name::dynamic::Field::new(#field_name, #field_type_ref, move |ctx| {
+                #crate_name::dynamic::FieldFuture::new(async move {
+                    #graphql_args_definition
+                    #execute
+                    #map_value
+                    #resolve
+                })
+            });
+        })
     }
     fn get_execute_code(&self) -> darling::Result<TokenStream> {
         let ty = self.ctx.expand_ty.as_ref().unwrap_or_else(|| {
@@ -276,7 +350,12 @@ impl FieldImplementor for ExpandObjectFieldsMethod {
     }
 
     fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
-        common::get_argument_definitions(self.get_args()?)
+        let check_flatten_args = common::get_check_flatten_args_code(self)?;
+        let argument_definitions = common::get_argument_definitions(self.get_args()?)?;
+        Ok(quote! {
+            #check_flatten_args
+            #argument_definitions
+        })
     }
 
     fn get_field_description_code(&self) -> darling::Result<TokenStream> {
@@ -288,6 +367,11 @@ impl FieldImplementor for ExpandObjectFieldsMethod {
     }
 
     fn get_field_usage_code(&self) -> darling::Result<TokenStream> {
+        if self.ctx.interface {
+            return Ok(quote! {
+                let object = object.field(field);
+            });
+        }
         let field_var_ident = get_field_var_ident(self.index, &self.ident);
 
         Ok(quote! {
@@ -320,8 +404,8 @@ where
     }
 }
 
-fn use_field_code(index: usize, method: &ExpandObjectFieldsMethod) -> darling::Result<TokenStream> {
-    let field_var_ident = get_field_var_ident(index, &method.ident);
+fn use_field_code(method: &ExpandObjectFieldsMethod) -> darling::Result<TokenStream> {
+    let field_var_ident = get_field_var_ident(method.index, &method.ident);
 
     Ok(quote! {
         let object = object.field(#field_var_ident);
@@ -333,8 +417,7 @@ fn use_fields_code(expand: &ExpandObjectFields) -> darling::Result<TokenStream>
         .get_fields()?
         .iter()
         .filter(|method| !method.get_skip())
-        .enumerate()
-        .map(|(index, method)| use_field_code(index, method).into_token_stream())
+        .map(|method| use_field_code(method).into_token_stream())
         .collect())
 }
 
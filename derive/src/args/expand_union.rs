@@ -0,0 +1,212 @@
+use darling::FromAttributes;
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::quote;
+use syn::Generics;
+use syn::Path;
+use syn::Type;
+
+use crate::args::common;
+use crate::utils::common::CommonField;
+use crate::utils::common::CommonObject;
+use crate::utils::common::EMPTY_ARGS;
+use crate::utils::common::GetArgs;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseEnum;
+use crate::utils::derive_types::NewtypeVariant;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::path_attr::PathAttr;
+use crate::utils::register_attr::RegisterAttr;
+use crate::utils::type_utils::get_owned_type;
+use crate::utils::with_attributes::WithAttributes;
+
+from_variant!(ExpandUnionItem, NewtypeVariant,);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct ExpandUnionAttrs {
+    pub target: PathAttr,
+
+    #[darling(default, multiple)]
+    #[darling(rename = "register")]
+    pub registers: Vec<RegisterAttr>,
+}
+
+from_derive_input!(
+    ExpandUnion,
+    WithAttributes<ExpandUnionAttrs, BaseEnum<ExpandUnionItem, Generics>>,
+);
+
+impl CommonObject for ExpandUnion {
+    fn get_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn should_impl_type_name(&self) -> bool {
+        false
+    }
+
+    fn get_ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    fn get_type(&self) -> darling::Result<Path> {
+        Ok(self.ident.clone().into())
+    }
+
+    fn get_generics(&self) -> darling::Result<&Generics> {
+        Ok(&self.generics)
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+impl GetFields<ExpandUnionItem> for ExpandUnion {
+    fn get_fields(&self) -> darling::Result<&Vec<ExpandUnionItem>> {
+        Ok(&self.data)
+    }
+}
+
+impl GetArgs<()> for ExpandUnionItem {
+    fn get_args(&self) -> darling::Result<&Vec<()>> {
+        Ok(&EMPTY_ARGS)
+    }
+}
+
+impl CommonField for ExpandUnionItem {
+    fn get_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_ident(&self) -> darling::Result<&Ident> {
+        Ok(&self.ident)
+    }
+
+    fn get_type(&self) -> darling::Result<&Type> {
+        Ok(&self.fields.ty)
+    }
+
+    fn get_skip(&self) -> bool {
+        false
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+fn check_generics(expand: &ExpandUnion) -> darling::Result<()> {
+    if expand.generics.params.is_empty() {
+        return Ok(());
+    }
+    Err(darling::Error::custom("ExpandUnion does not support generics").with_span(&expand.generics))
+}
+
+fn impl_expand_union(expand: &ExpandUnion) -> darling::Result<TokenStream> {
+    check_generics(expand)?;
+    let crate_name = get_crate_name();
+    let ident = &expand.ident;
+    let target = &expand.attrs.target.0;
+    let name = ident.to_string();
+
+    let marks = expand.data.iter().map(|item| {
+        let ty = get_owned_type(&item.fields.ty);
+        quote! {
+            impl #crate_name::internal::UnionMark<#target> for #ty {}
+        }
+    });
+
+    Ok(quote! {
+        impl #crate_name::internal::ExpandUnion for #ident {
+            type Union = #target;
+            fn get_expand_union_name() -> std::borrow::Cow<'static, str> {
+                #name.into()
+            }
+        }
+        #(#marks)*
+    })
+}
+
+fn impl_into_union_instance(expand: &ExpandUnion) -> darling::Result<TokenStream> {
+    check_generics(expand)?;
+    let crate_name = get_crate_name();
+    let ident = &expand.ident;
+    let target = &expand.attrs.target.0;
+
+    let arms = expand.data.iter().map(|item| {
+        let variant_ident = &item.ident;
+        quote! {
+            #ident::#variant_ident(value) => #crate_name::UnionInstance::new_owned(value),
+        }
+    });
+
+    Ok(quote! {
+        impl From<#ident> for #crate_name::UnionInstance<'static, #target> {
+            fn from(value: #ident) -> Self {
+                match value {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+fn define_items(expand: &ExpandUnion) -> TokenStream {
+    let crate_name = get_crate_name();
+    let items = expand.data.iter().map(|item| {
+        let ty = get_owned_type(&item.fields.ty);
+        quote! {
+            let object = object.possible_type(<#ty as #crate_name::internal::Object>::get_object_type_name().as_ref());
+        }
+    });
+    quote! {
+        #(#items)*
+    }
+}
+
+fn impl_register(expand: &ExpandUnion) -> darling::Result<TokenStream> {
+    check_generics(expand)?;
+    let crate_name = get_crate_name();
+    let ident = &expand.ident;
+    let register_nested_types = common::get_nested_type_register_code(expand).into_token_stream();
+    let define_items = define_items(expand);
+    let register_attr = &expand.attrs.registers;
+
+    Ok(quote! {
+        impl #crate_name::internal::Register for #ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+
+                #( #register_attr )*
+
+                #register_nested_types
+
+                registry.update_union(
+                    <<Self as #crate_name::internal::ExpandUnion>::Union as #crate_name::internal::Union>::get_union_type_name().as_ref(),
+                    <Self as #crate_name::internal::ExpandUnion>::get_expand_union_name().as_ref(),
+                    |object| {
+                        #define_items
+                        object
+                    },
+                )
+            }
+        }
+    })
+}
+
+impl ToTokens for ExpandUnion {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_expand_union = impl_expand_union(self).into_token_stream();
+        let impl_into_union_instance = impl_into_union_instance(self).into_token_stream();
+        let impl_register = impl_register(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_expand_union
+            #impl_into_union_instance
+            #impl_register
+        });
+    }
+}
@@ -0,0 +1,373 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::format_ident;
+use quote::quote;
+use syn::Generics;
+
+use crate::args::common;
+use crate::utils::common::CommonField;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseStruct;
+use crate::utils::derive_types::NamedField;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::WithContext;
+use crate::utils::with_doc::WithDoc;
+
+const COMBINATORS: [&str; 3] = ["and", "or", "not"];
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct FilterInputFieldAttrs {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct FilterInputFieldContext {
+    pub rename_fields: Option<RenameRule>,
+}
+
+from_field!(
+    FilterInputField,
+    WithAttributes<
+        WithDoc<FilterInputFieldAttrs>,
+        WithContext<FilterInputFieldContext, NamedField>,
+    >,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct FilterInputAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub rename_fields: Option<RenameRule>,
+}
+
+from_derive_input!(
+    FilterInput,
+    WithAttributes<FilterInputAttrs, BaseStruct<FilterInputField, Generics>>,
+    ctx,
+);
+
+impl MakeContext<FilterInputFieldContext> for FilterInput {
+    fn make_context(&self) -> FilterInputFieldContext {
+        FilterInputFieldContext {
+            rename_fields: self.attrs.rename_fields,
+        }
+    }
+}
+
+impl CommonField for FilterInputField {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn get_ident(&self) -> darling::Result<&syn::Ident> {
+        Ok(&self.ident)
+    }
+
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        Ok(&self.ty)
+    }
+
+    fn get_skip(&self) -> bool {
+        self.attrs.skip
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_fields.as_ref()
+    }
+}
+
+impl GetFields<FilterInputField> for FilterInput {
+    fn get_fields(&self) -> darling::Result<&Vec<FilterInputField>> {
+        Ok(&self.data.fields)
+    }
+}
+
+fn get_filter_ident(object: &FilterInput) -> syn::Ident {
+    format_ident!("{}Filter", object.ident)
+}
+
+fn get_filter_name(object: &FilterInput) -> String {
+    object
+        .attrs
+        .name
+        .clone()
+        .unwrap_or_else(|| get_filter_ident(object).to_string())
+}
+
+fn get_filter_fields(object: &FilterInput) -> darling::Result<Vec<&FilterInputField>> {
+    if !object.generics.params.is_empty() {
+        return Err(
+            darling::Error::custom("FilterInput does not support generics")
+                .with_span(&object.generics),
+        );
+    }
+    let fields: Vec<_> = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip())
+        .collect();
+    for field in &fields {
+        let name = common::get_input_field_name(*field)?;
+        if COMBINATORS.contains(&name.as_str())
+            || COMBINATORS.contains(&field.ident.to_string().as_str())
+        {
+            return Err(darling::Error::custom(format!(
+                "field `{}` conflicts with the `{}` filter combinator, rename or skip it",
+                field.ident, name
+            ))
+            .with_span(&field.ident));
+        }
+    }
+    Ok(fields)
+}
+
+fn get_filter_type(field: &FilterInputField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ty = common::get_field_type(field)?;
+    Ok(quote!(<#ty as #crate_name::Filterable>::Filter))
+}
+
+fn define_filter_struct(object: &FilterInput) -> darling::Result<TokenStream> {
+    let vis = &object.vis;
+    let filter_ident = get_filter_ident(object);
+    let doc = format!("Filter input generated for [`{}`]", object.ident);
+    let fields = get_filter_fields(object)?
+        .into_iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let filter_type = get_filter_type(field)?;
+            Ok(quote!(#vis #ident: Option<#filter_type>,))
+        })
+        .collect::<darling::Result<Vec<_>>>()?;
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Default)]
+        #vis struct #filter_ident {
+            #(#fields)*
+            #vis and: Option<Vec<#filter_ident>>,
+            #vis or: Option<Vec<#filter_ident>>,
+            #vis not: Option<Box<#filter_ident>>,
+        }
+    })
+}
+
+fn impl_input_object(object: &FilterInput) -> TokenStream {
+    let crate_name = get_crate_name();
+    let filter_ident = get_filter_ident(object);
+    let name = get_filter_name(object);
+    quote! {
+        impl #crate_name::internal::TypeName for #filter_ident {
+            fn get_type_name() -> std::borrow::Cow<'static, str> {
+                #name.into()
+            }
+        }
+        impl #crate_name::internal::InputTypeName for #filter_ident {}
+        impl #crate_name::internal::InputObject for #filter_ident {}
+    }
+}
+
+fn impl_register(object: &FilterInput) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let filter_ident = get_filter_ident(object);
+    let define_object = common::impl_define_input_object();
+    let register_object_code = common::register_object_code();
+
+    let mut register_types = Vec::new();
+    let mut define_fields = Vec::new();
+    for field in get_filter_fields(object)? {
+        let filter_type = get_filter_type(field)?;
+        let field_name = common::get_input_field_name(field)?;
+        let field_description = common::field_description(field)?;
+        register_types.push(quote! {
+            let registry = registry.register::<#filter_type>();
+        });
+        define_fields.push(quote! {
+            let field = #crate_name::dynamic::InputValue::new(
+                #field_name,
+                <Option<#filter_type> as #crate_name::internal::GetInputTypeRef>::get_input_type_ref(),
+            );
+            #field_description
+            let object = object.field(field);
+        });
+    }
+
+    Ok(quote! {
+        impl #crate_name::internal::Register for #filter_ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #(#register_types)*
+
+                #define_object
+
+                #(#define_fields)*
+
+                let object = object.field(#crate_name::dynamic::InputValue::new(
+                    "and",
+                    <Option<Vec<Self>> as #crate_name::internal::GetInputTypeRef>::get_input_type_ref(),
+                ));
+                let object = object.field(#crate_name::dynamic::InputValue::new(
+                    "or",
+                    <Option<Vec<Self>> as #crate_name::internal::GetInputTypeRef>::get_input_type_ref(),
+                ));
+                let object = object.field(#crate_name::dynamic::InputValue::new(
+                    "not",
+                    <Option<Self> as #crate_name::internal::GetInputTypeRef>::get_input_type_ref(),
+                ));
+
+                #register_object_code
+            }
+        }
+    })
+}
+
+fn impl_from_value(object: &FilterInput) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let filter_ident = get_filter_ident(object);
+    let fields_value = get_filter_fields(object)?
+        .into_iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let field_name = common::get_input_field_name(field)?;
+            Ok(quote! {
+                #ident: #crate_name::internal::FromValue::from_value(__object.try_get(#field_name)).map_err(|e| e.into_field_error(#field_name))?,
+            })
+        })
+        .collect::<darling::Result<Vec<_>>>()?;
+    Ok(quote! {
+        impl #crate_name::internal::FromValue for #filter_ident {
+            fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
+                let __value = __value?;
+                let __object = __value.object()?;
+                let not: Option<Self> = #crate_name::internal::FromValue::from_value(__object.try_get("not")).map_err(|e| e.into_field_error("not"))?;
+                Ok(Self {
+                    #(#fields_value)*
+                    and: #crate_name::internal::FromValue::from_value(__object.try_get("and")).map_err(|e| e.into_field_error("and"))?,
+                    or: #crate_name::internal::FromValue::from_value(__object.try_get("or")).map_err(|e| e.into_field_error("or"))?,
+                    not: not.map(Box::new),
+                })
+            }
+        }
+    })
+}
+
+fn impl_filter(object: &FilterInput) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let filter_ident = get_filter_ident(object);
+
+    let mut fields_matches = Vec::new();
+    let mut fields_predicate = Vec::new();
+    for field in get_filter_fields(object)? {
+        let field_ident = &field.ident;
+        let ty = common::get_field_type(field)?;
+        let filter_type = get_filter_type(field)?;
+        let path = field_ident.to_string();
+        fields_matches.push(quote! {
+            && self.#field_ident.as_ref().is_none_or(|filter| {
+                <#filter_type as #crate_name::FieldFilter<#ty>>::matches(filter, value.map(|value| &value.#field_ident))
+            })
+        });
+        fields_predicate.push(quote! {
+            if let Some(filter) = &self.#field_ident {
+                predicates.push(#crate_name::Filter::to_predicate(
+                    filter,
+                    &#crate_name::internal::get_filter_field_path(field, #path),
+                ));
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #crate_name::Filter for #filter_ident {
+            fn to_predicate(&self, field: &str) -> #crate_name::FilterPredicate {
+                let mut predicates = Vec::new();
+                #(#fields_predicate)*
+                if let Some(filters) = &self.and {
+                    predicates.extend(filters.iter().map(|filter| #crate_name::Filter::to_predicate(filter, field)));
+                }
+                if let Some(filters) = &self.or {
+                    predicates.push(#crate_name::FilterPredicate::Or(
+                        filters.iter().map(|filter| #crate_name::Filter::to_predicate(filter, field)).collect(),
+                    ));
+                }
+                if let Some(filter) = &self.not {
+                    predicates.push(#crate_name::FilterPredicate::Not(Box::new(
+                        #crate_name::Filter::to_predicate(filter.as_ref(), field),
+                    )));
+                }
+                #crate_name::FilterPredicate::and(predicates)
+            }
+        }
+
+        impl #crate_name::FieldFilter<#ident> for #filter_ident {
+            fn matches(&self, value: Option<&#ident>) -> bool {
+                true
+                #(#fields_matches)*
+                && self.and.as_ref().is_none_or(|filters| {
+                    filters.iter().all(|filter| #crate_name::FieldFilter::<#ident>::matches(filter, value))
+                })
+                && self.or.as_ref().is_none_or(|filters| {
+                    filters.iter().any(|filter| #crate_name::FieldFilter::<#ident>::matches(filter, value))
+                })
+                && self.not.as_ref().is_none_or(|filter| {
+                    !#crate_name::FieldFilter::<#ident>::matches(filter.as_ref(), value)
+                })
+            }
+        }
+
+        impl #filter_ident {
+            /// Whether `item` matches the filter
+            pub fn matches(&self, item: &#ident) -> bool {
+                #crate_name::FieldFilter::<#ident>::matches(self, Some(item))
+            }
+
+            /// The filter as a predicate over the fields of the filtered type
+            pub fn to_predicate(&self) -> #crate_name::FilterPredicate {
+                #crate_name::Filter::to_predicate(self, "")
+            }
+        }
+
+        impl #crate_name::Filterable for #ident {
+            type Filter = #filter_ident;
+        }
+    })
+}
+
+impl ToTokens for FilterInput {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Err(err) = get_filter_fields(self) {
+            tokens.extend(err.write_errors());
+            return;
+        }
+        let define_filter_struct = define_filter_struct(self).into_token_stream();
+        let impl_input_object = impl_input_object(self);
+        let impl_register = impl_register(self).into_token_stream();
+        let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_filter = impl_filter(self).into_token_stream();
+        tokens.extend(quote! {
+            #define_filter_struct
+            #impl_input_object
+            #impl_register
+            #impl_from_value
+            #impl_filter
+        });
+    }
+}
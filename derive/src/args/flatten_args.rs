@@ -0,0 +1,143 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::quote;
+use syn::Generics;
+use syn::Path;
+
+use crate::args::common;
+use crate::utils::common::CommonField;
+use crate::utils::common::CommonObject;
+use crate::utils::common::EMPTY_ARGS;
+use crate::utils::common::GetArgs;
+use crate::utils::common::GetFields;
+use crate::utils::derive_types::BaseStruct;
+use crate::utils::derive_types::NamedField;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::WithContext;
+use crate::utils::with_doc::WithDoc;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct ArgsFieldAttrs {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub flatten: bool,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ArgsFieldContext {
+    pub rename_args: Option<RenameRule>,
+}
+
+from_field!(
+    ArgsField,
+    WithAttributes<WithDoc<ArgsFieldAttrs>, WithContext<ArgsFieldContext, NamedField>>,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct ArgsAttrs {
+    #[darling(default)]
+    pub rename_args: Option<RenameRule>,
+}
+
+from_derive_input!(
+    Args,
+    WithAttributes<ArgsAttrs, BaseStruct<ArgsField, Generics>>,
+    ctx,
+);
+
+impl MakeContext<ArgsFieldContext> for Args {
+    fn make_context(&self) -> ArgsFieldContext {
+        ArgsFieldContext {
+            rename_args: self.attrs.rename_args,
+        }
+    }
+}
+
+impl CommonObject for Args {
+    fn get_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn should_impl_type_name(&self) -> bool {
+        false
+    }
+
+    fn get_ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    fn get_type(&self) -> darling::Result<Path> {
+        Ok(self.ident.clone().into())
+    }
+
+    fn get_generics(&self) -> darling::Result<&Generics> {
+        Ok(&self.generics)
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+impl CommonField for ArgsField {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn get_ident(&self) -> darling::Result<&syn::Ident> {
+        Ok(&self.ident)
+    }
+
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        Ok(&self.ty)
+    }
+
+    fn get_skip(&self) -> bool {
+        self.attrs.skip
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_args.as_ref()
+    }
+
+    fn get_flatten(&self) -> bool {
+        self.attrs.flatten
+    }
+}
+
+impl GetFields<ArgsField> for Args {
+    fn get_fields(&self) -> darling::Result<&Vec<ArgsField>> {
+        Ok(&self.data.fields)
+    }
+}
+
+impl GetArgs<()> for ArgsField {
+    fn get_args(&self) -> darling::Result<&Vec<()>> {
+        Ok(&EMPTY_ARGS)
+    }
+}
+
+impl ToTokens for Args {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_flatten_args = common::impl_flatten_args(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_flatten_args
+        });
+    }
+}
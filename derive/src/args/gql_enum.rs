@@ -35,6 +35,12 @@ pub struct EnumVariantAttributes {
 
     #[darling(default)]
     deprecation: Deprecation,
+
+    /// catch-all for values converted from the remote enum, it is not
+    /// registered in the schema and can't be parsed from or resolved to a
+    /// GraphQL value
+    #[darling(default)]
+    other: bool,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -63,6 +69,9 @@ pub struct EnumAttributes {
     #[darling(default)]
     pub remote: Option<PathAttr>,
 
+    #[darling(default)]
+    pub fallback: Option<PathAttr>,
+
     #[darling(default, multiple)]
     #[darling(rename = "register")]
     pub registers: Vec<RegisterAttr>,
@@ -122,7 +131,8 @@ impl CommonField for EnumVariant {
     }
 
     fn get_skip(&self) -> bool {
-        false
+        // the catch-all variant is not part of the GraphQL enum
+        self.attrs.other
     }
 
     fn get_doc(&self) -> darling::Result<Option<String>> {
@@ -144,6 +154,18 @@ impl GetFields<EnumVariant> for Enum {
     }
 }
 
+fn get_other_variant(enm: &Enum) -> darling::Result<Option<&EnumVariant>> {
+    let mut others = enm.data.iter().filter(|variant| variant.attrs.other);
+    let other = others.next();
+    if let Some(duplicate) = others.next() {
+        return Err(
+            darling::Error::custom("Only one variant can be marked as `other`")
+                .with_span(&duplicate.ident),
+        );
+    }
+    Ok(other)
+}
+
 fn impl_enum(enm: &impl CommonObject) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let enum_ident = enm.get_ident();
@@ -183,33 +205,47 @@ fn impl_into_value_match_item(
     })
 }
 
-fn impl_into_value_match_items<T, F>(enm: &T) -> darling::Result<TokenStream>
-where
-    T: GetFields<F> + CommonObject,
-    F: CommonField,
-{
+fn impl_into_value_match_items(enm: &Enum) -> darling::Result<TokenStream> {
     Ok(enm
-        .get_fields()?
+        .data
         .iter()
+        .filter(|variant| !variant.attrs.other)
         .map(|variant| impl_into_value_match_item(enm, variant).into_token_stream())
         .collect())
 }
 
-fn impl_into_value<T, F>(enm: &T) -> darling::Result<TokenStream>
-where
-    T: GetFields<F> + CommonObject,
-    F: CommonField,
-{
+fn impl_into_value(enm: &Enum) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let enum_ident = enm.get_ident();
     let match_items = impl_into_value_match_items(enm)?;
 
+    let Some(other) = get_other_variant(enm)? else {
+        return Ok(quote! {
+            impl From<&#enum_ident> for #crate_name::Value {
+                fn from(value: &#enum_ident) -> Self {
+                    match value {
+                        #match_items
+                    }
+                }
+            }
+        });
+    };
+    // the catch-all variant is not registered, so it has no GraphQL value
+    let other_ident = &other.ident;
     Ok(quote! {
-        impl From<&#enum_ident> for #crate_name::Value {
-            fn from(value: &#enum_ident) -> Self {
-                match value {
+        impl TryFrom<&#enum_ident> for #crate_name::Value {
+            type Error = #crate_name::Error;
+            fn try_from(value: &#enum_ident) -> Result<Self, Self::Error> {
+                Ok(match value {
                     #match_items
-                }
+                    #enum_ident::#other_ident => {
+                        return Err(#crate_name::Error::new(format!(
+                            "Variant `{}` of enum `{}` has no GraphQL value",
+                            stringify!(#other_ident),
+                            <#enum_ident as #crate_name::internal::Enum>::get_enum_type_name().as_ref(),
+                        )));
+                    }
+                })
             }
         }
     })
@@ -238,6 +274,7 @@ where
     Ok(enm
         .get_fields()?
         .iter()
+        .filter(|variant| !variant.get_skip())
         .map(|variant| get_from_value_match_item(enm, variant).into_token_stream())
         .collect())
 }
@@ -263,6 +300,33 @@ fn impl_from_value(enm: &Enum) -> darling::Result<TokenStream> {
     })
 }
 
+fn impl_resolve(enm: &Enum) -> darling::Result<TokenStream> {
+    if get_other_variant(enm)?.is_none() {
+        let impl_resolve_owned = common::impl_resolve_owned_by_value(enm)?;
+        let impl_resolve_ref = common::impl_resolve_ref_by_value(enm)?;
+        return Ok(quote! {
+            #impl_resolve_owned
+            #impl_resolve_ref
+        });
+    }
+    // the catch-all variant is not registered, so it can't be resolved
+    let crate_name = get_crate_name();
+    let enum_ident = enm.get_ident();
+
+    Ok(quote! {
+        impl<'__dynamic_graphql_lifetime> #crate_name::internal::ResolveOwned<'__dynamic_graphql_lifetime> for #enum_ident {
+            fn resolve_owned(self, _ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<'__dynamic_graphql_lifetime>>> {
+                Ok(Some(#crate_name::FieldValue::value(#crate_name::Value::try_from(&self)?)))
+            }
+        }
+        impl<'__dynamic_graphql_lifetime> #crate_name::internal::ResolveRef<'__dynamic_graphql_lifetime> for #enum_ident {
+            fn resolve_ref(&'__dynamic_graphql_lifetime self, _ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<'__dynamic_graphql_lifetime>>> {
+                Ok(Some(#crate_name::FieldValue::value(#crate_name::Value::try_from(self)?)))
+            }
+        }
+    })
+}
+
 fn impl_into_remote_item(
     enum_ident: &syn::Ident,
     remote_path: &syn::Path,
@@ -279,8 +343,31 @@ fn impl_into_remote(enm: &Enum, remote_path: &syn::Path) -> darling::Result<Toke
     let matches: TokenStream = enm
         .data
         .iter()
+        .filter(|item| !item.attrs.other)
         .map(|item| impl_into_remote_item(enum_ident, remote_path, item).into_token_stream())
         .collect();
+    if let Some(other) = get_other_variant(enm)? {
+        // the catch-all variant has no remote counterpart
+        let crate_name = get_crate_name();
+        let other_ident = &other.ident;
+        return Ok(quote! {
+            impl TryFrom<#enum_ident> for #remote_path {
+                type Error = #crate_name::Error;
+                fn try_from(value: #enum_ident) -> Result<Self, Self::Error> {
+                    Ok(match value {
+                        #matches
+                        #enum_ident::#other_ident => {
+                            return Err(#crate_name::Error::new(format!(
+                                "Variant `{}` of enum `{}` has no remote equivalent",
+                                stringify!(#other_ident),
+                                stringify!(#enum_ident),
+                            )));
+                        }
+                    })
+                }
+            }
+        });
+    }
     Ok(quote! {
         impl From<#enum_ident> for #remote_path {
             fn from(value: #enum_ident) -> Self {
@@ -308,13 +395,30 @@ fn impl_from_remote(enm: &Enum, remote_path: &syn::Path) -> darling::Result<Toke
     let matches: TokenStream = enm
         .data
         .iter()
+        .filter(|item| !item.attrs.other)
         .map(|item| impl_from_remote_item(enum_ident, remote_path, item).into_token_stream())
         .collect();
+    let unknown = match (&enm.attrs.fallback, get_other_variant(enm)?) {
+        (Some(fallback), _) => {
+            let fallback = &fallback.0;
+            Some(quote!(value => #fallback(value),))
+        }
+        (None, Some(other)) => {
+            let other_ident = &other.ident;
+            Some(quote!(_ => #enum_ident::#other_ident,))
+        }
+        (None, None) => None,
+    };
+    let allow = unknown
+        .is_some()
+        .then(|| quote!(#[allow(unreachable_patterns)]));
     Ok(quote! {
         impl From<#remote_path> for #enum_ident {
             fn from(value: #remote_path) -> Self {
+                #allow
                 match value {
                     #matches
+                    #unknown
                 }
             }
         }
@@ -323,6 +427,12 @@ fn impl_from_remote(enm: &Enum, remote_path: &syn::Path) -> darling::Result<Toke
 
 fn impl_remote(enm: &Enum) -> darling::Result<TokenStream> {
     let Some(remote) = &enm.attrs.remote else {
+        if let Some(fallback) = &enm.attrs.fallback {
+            return Err(
+                darling::Error::custom("`fallback` can only be used with `remote`")
+                    .with_span(&fallback.0),
+            );
+        }
         return Ok(quote! {});
     };
     let remote_path = &remote.0;
@@ -358,6 +468,7 @@ where
     Ok(enm
         .get_fields()?
         .iter()
+        .filter(|variant| !variant.get_skip())
         .map(|variant| register_item(variant).into_token_stream())
         .collect())
 }
@@ -387,16 +498,14 @@ impl ToTokens for Enum {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let impl_enum = impl_enum(self).into_token_stream();
         let impl_into_value = impl_into_value(self).into_token_stream();
-        let impl_resolve_owned = common::impl_resolve_owned_by_value(self).into_token_stream();
-        let impl_resolve_ref = common::impl_resolve_ref_by_value(self).into_token_stream();
+        let impl_resolve = impl_resolve(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
         let impl_remote = impl_remote(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         tokens.extend(quote! {
             #impl_enum
             #impl_into_value
-            #impl_resolve_owned
-            #impl_resolve_ref
+            #impl_resolve
             #impl_from_value
             #impl_remote
             #impl_register
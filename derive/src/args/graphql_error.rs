@@ -0,0 +1,192 @@
+use darling::FromAttributes;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::format_ident;
+use quote::quote;
+use syn::Generics;
+
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseEnum;
+use crate::utils::derive_types::BaseField;
+use crate::utils::derive_types::BaseVariant;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::rename_rule::calc_enum_item_name;
+use crate::utils::rename_rule::calc_field_name;
+use crate::utils::with_attributes::WithAttributes;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct GraphQLErrorFieldAttrs {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+}
+
+from_field!(
+    GraphQLErrorField,
+    WithAttributes<GraphQLErrorFieldAttrs, BaseField>,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct GraphQLErrorVariantAttrs {
+    #[darling(default)]
+    pub code: Option<String>,
+
+    #[darling(default)]
+    pub message: Option<String>,
+}
+
+from_variant!(
+    GraphQLErrorVariant,
+    WithAttributes<GraphQLErrorVariantAttrs, BaseVariant<GraphQLErrorField>>,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct GraphQLErrorAttrs {
+    #[darling(default)]
+    pub rename_fields: Option<RenameRule>,
+}
+
+from_derive_input!(
+    GraphQLError,
+    WithAttributes<GraphQLErrorAttrs, BaseEnum<GraphQLErrorVariant, Generics>>,
+);
+
+/// The binding of a variant field, `__0`, `__1`... for tuple fields
+fn get_field_binding(index: usize, field: &GraphQLErrorField) -> syn::Ident {
+    match &field.ident {
+        Some(ident) => ident.clone(),
+        None => format_ident!("__{}", index),
+    }
+}
+
+/// The extension key of a field, tuple fields are only added when they are named
+fn get_extension_name(error: &GraphQLError, field: &GraphQLErrorField) -> Option<String> {
+    if field.attrs.skip {
+        return None;
+    }
+    match &field.ident {
+        Some(ident) => Some(calc_field_name(
+            field.attrs.name.as_deref(),
+            &ident.to_string(),
+            error.attrs.rename_fields.as_ref(),
+        )),
+        None => field.attrs.name.clone(),
+    }
+}
+
+/// Point positional arguments (`{0}`) of the message to the tuple field bindings
+fn bind_positional_arguments(message: &str) -> String {
+    let mut result = String::with_capacity(message.len());
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        result.push(c);
+        if c != '{' {
+            continue;
+        }
+        if chars.peek() == Some(&'{') {
+            result.push(chars.next().unwrap());
+            continue;
+        }
+        if chars.peek().is_some_and(|c| c.is_ascii_digit()) {
+            result.push_str("__");
+        }
+    }
+    result
+}
+
+fn define_variant_arm(
+    error: &GraphQLError,
+    variant: &GraphQLErrorVariant,
+) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &error.ident;
+    let variant_ident = &variant.ident;
+    let message = variant.attrs.message.as_deref().ok_or_else(|| {
+        darling::Error::custom("GraphQLError variants require a `message`").with_span(variant_ident)
+    })?;
+    let message = syn::LitStr::new(&bind_positional_arguments(message), Span::call_site());
+    let code = calc_enum_item_name(
+        variant.attrs.code.as_deref(),
+        &variant_ident.to_string(),
+        None,
+    );
+
+    let fields: Vec<_> = variant.fields.iter().enumerate().collect();
+    let bindings = fields
+        .iter()
+        .map(|(index, field)| get_field_binding(*index, field));
+    let pattern = match variant.fields.style {
+        darling::ast::Style::Struct => quote!(#ident::#variant_ident { #(#bindings),* }),
+        darling::ast::Style::Tuple => quote!(#ident::#variant_ident ( #(#bindings),* )),
+        darling::ast::Style::Unit => quote!(#ident::#variant_ident),
+    };
+    let set_extensions = fields.iter().filter_map(|(index, field)| {
+        let name = get_extension_name(error, field)?;
+        let binding = get_field_binding(*index, field);
+        Some(quote! {
+            extensions.set(#name, #binding);
+        })
+    });
+
+    Ok(quote! {
+        #[allow(unused_variables)]
+        #pattern => {
+            let mut error = #crate_name::Error::new(format!(#message));
+            let extensions = error.extensions.get_or_insert_with(Default::default);
+            extensions.set("code", #code);
+            #(#set_extensions)*
+            error
+        }
+    })
+}
+
+fn impl_into_error(error: &GraphQLError) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &error.ident;
+    if !error.generics.params.is_empty() {
+        return Err(
+            darling::Error::custom("GraphQLError does not support generics")
+                .with_span(&error.generics),
+        );
+    }
+    let mut errors = darling::Error::accumulator();
+    let arms: Vec<_> = error
+        .data
+        .iter()
+        .filter_map(|variant| errors.handle(define_variant_arm(error, variant)))
+        .collect();
+    errors.finish()?;
+
+    Ok(quote! {
+        impl From<#ident> for #crate_name::Error {
+            fn from(value: #ident) -> Self {
+                match value {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl<T: #crate_name::internal::GetInputTypeRef> From<#ident> for #crate_name::internal::InputValueError<T> {
+            fn from(value: #ident) -> Self {
+                #crate_name::Error::from(value).into()
+            }
+        }
+    })
+}
+
+impl ToTokens for GraphQLError {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let impl_into_error = impl_into_error(self).into_token_stream();
+        tokens.extend(quote! {
+            #impl_into_error
+        });
+    }
+}
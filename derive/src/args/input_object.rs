@@ -31,6 +31,9 @@ pub struct InputObjectFieldAttrs {
 
     #[darling(default)]
     pub name: Option<String>,
+
+    #[darling(default)]
+    pub flatten: bool,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -128,6 +131,10 @@ impl CommonField for InputObjectField {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
+
+    fn get_flatten(&self) -> bool {
+        self.attrs.flatten
+    }
 }
 
 impl GetFields<InputObjectField> for InputObject {
@@ -143,6 +150,13 @@ impl GetArgs<()> for InputObjectField {
 }
 
 fn get_define_field(field: &impl CommonField) -> darling::Result<TokenStream> {
+    if field.get_flatten() {
+        let crate_name = get_crate_name();
+        let ty = common::get_field_type(field)?;
+        return Ok(quote! {
+            let object = <#ty as #crate_name::internal::FlattenInputObject>::flatten_fields(object);
+        });
+    }
     let description = common::field_description(field)?;
     let get_new_input_value_code = common::get_new_input_value_code(field)?;
     Ok(quote! {
@@ -165,31 +179,78 @@ where
         .collect())
 }
 
+fn get_flatten_fields<O>(object: &O) -> darling::Result<Vec<&InputObjectField>>
+where
+    O: GetFields<InputObjectField>,
+{
+    Ok(object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip() && field.get_flatten())
+        .collect())
+}
+
+fn get_register_flatten_types_code<O>(object: &O) -> darling::Result<TokenStream>
+where
+    O: GetFields<InputObjectField>,
+{
+    let crate_name = get_crate_name();
+    get_flatten_fields(object)?
+        .into_iter()
+        .map(|field| {
+            let ty = common::get_field_type(field)?;
+            Ok(quote! {
+                let registry = <#ty as #crate_name::internal::FlattenInputObject>::register_flatten_types(registry);
+            })
+        })
+        .collect()
+}
+
+fn get_check_flatten_fields_code(object: &InputObject) -> darling::Result<TokenStream> {
+    if get_flatten_fields(object)?.is_empty() {
+        return Ok(quote!());
+    }
+    let crate_name = get_crate_name();
+    Ok(quote! {
+        #crate_name::internal::check_flatten_field_names(
+            &<Self as #crate_name::internal::InputObject>::get_input_object_type_name(),
+            <Self as #crate_name::internal::FlattenInputObject>::get_flatten_field_names(),
+        );
+    })
+}
+
 fn impl_register(object: &InputObject) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = &object.ident;
-    let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
+    let check_flatten_fields = get_check_flatten_fields_code(object)?;
 
     let define_object = common::impl_define_input_object();
-    let define_fields = get_define_fields(object)?;
     let description = common::object_description(object.get_doc()?.as_deref())?;
     let register_object_code = common::register_object_code();
 
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
     let register_attr = &object.attrs.registers;
 
     Ok(quote! {
-        impl #crate_name::internal::Register for #ident {
+        impl #impl_generics #crate_name::internal::Register for #ident #ty_generics #where_clause {
             fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
 
                 #( #register_attr )*
 
-                #register_nested_types
+                let registry = <Self as #crate_name::internal::FlattenInputObject>::register_flatten_types(registry);
 
                 #define_object
 
                 #description
 
-                #define_fields
+                #check_flatten_fields
+
+                let object = <Self as #crate_name::internal::FlattenInputObject>::flatten_fields(object);
+
+                let registry = registry.set_input_object_fields(
+                    &<Self as #crate_name::internal::InputObject>::get_input_object_type_name(),
+                    <Self as #crate_name::internal::FlattenInputObject>::get_flatten_field_names(),
+                );
 
                 #register_object_code
             }
@@ -211,19 +272,27 @@ fn get_field_value(index: usize, field: &InputObjectField) -> darling::Result<To
             let #item = Default::default();
         });
     }
+    if field.get_flatten() {
+        let ty = common::get_field_type(field)?;
+        return Ok(quote! {
+            let #item = <#ty as #crate_name::internal::FlattenInputObject>::from_flatten_object(&__object)?;
+        });
+    }
     Ok(quote! {
         let #item = #crate_name::internal::FromValue::from_value(__object.try_get(#field_name)).map_err(|e| e.into_field_error(#field_name))?;
     })
 }
 
-fn get_fields_value(object: &InputObject) -> TokenStream {
-    object
-        .data
-        .fields
+fn get_fields_value<O>(object: &O) -> darling::Result<TokenStream>
+where
+    O: GetFields<InputObjectField>,
+{
+    Ok(object
+        .get_fields()?
         .iter()
         .enumerate()
         .map(|(index, field)| get_field_value(index, field).into_token_stream())
-        .collect()
+        .collect())
 }
 
 fn get_field_usage(index: usize, field: &impl CommonField) -> darling::Result<TokenStream> {
@@ -256,18 +325,72 @@ where
 fn impl_from_value(object: &InputObject) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = object.get_ident();
-    let fields_value = get_fields_value(object);
-    let fields_usage = get_fields_usage(object)?;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
     Ok(quote!(
-        impl #crate_name::internal::FromValue for #ident {
+        impl #impl_generics #crate_name::internal::FromValue for #ident #ty_generics #where_clause {
             fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
                 let __value = __value?;
                 let __object = __value.object()?;
+                Ok(<Self as #crate_name::internal::FlattenInputObject>::from_flatten_object(&__object)?)
+            }
+        }
+    ))
+}
+
+pub fn impl_flatten_input_object<O>(object: &O) -> darling::Result<TokenStream>
+where
+    O: CommonObject + GetFields<InputObjectField>,
+{
+    let crate_name = get_crate_name();
+    let ident = object.get_ident();
+    let (impl_generics, ty_generics, where_clause) = object.get_generics()?.split_for_impl();
+
+    let field_names = object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+        .map(|field| {
+            if field.get_flatten() {
+                let ty = common::get_field_type(field)?;
+                Ok(quote! {
+                    names.extend(<#ty as #crate_name::internal::FlattenInputObject>::get_flatten_field_names());
+                })
+            } else {
+                let name = common::get_input_field_name(field)?;
+                Ok(quote! {
+                    names.push(#name);
+                })
+            }
+        })
+        .collect::<darling::Result<TokenStream>>()?;
+    let register_nested_types = common::get_nested_type_register_code(object)?;
+    let register_flatten_types = get_register_flatten_types_code(object)?;
+    let define_fields = get_define_fields(object)?;
+    let fields_value = get_fields_value(object)?;
+    let fields_usage = get_fields_usage(object)?;
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::FlattenInputObject for #ident #ty_generics #where_clause {
+            fn get_flatten_field_names() -> Vec<&'static str> {
+                let mut names = Vec::new();
+                #field_names
+                names
+            }
+            fn register_flatten_types(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #register_nested_types
+                #register_flatten_types
+                registry
+            }
+            fn flatten_fields(object: #crate_name::dynamic::InputObject) -> #crate_name::dynamic::InputObject {
+                #define_fields
+                object
+            }
+            fn from_flatten_object(__object: &#crate_name::dynamic::ObjectAccessor<'_>) -> #crate_name::Result<Self> {
                 #fields_value
                 #fields_usage
             }
         }
-    ))
+    })
 }
 
 impl ToTokens for InputObject {
@@ -275,10 +398,14 @@ impl ToTokens for InputObject {
         let impl_object = common::impl_input_object(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_flatten_input_object = impl_flatten_input_object(self).into_token_stream();
+        let impl_flatten_args = common::impl_flatten_args(self).into_token_stream();
         tokens.extend(quote! {
             #impl_object
             #impl_register
             #impl_from_value
+            #impl_flatten_input_object
+            #impl_flatten_args
         });
     }
 }
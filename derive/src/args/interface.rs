@@ -1,13 +1,11 @@
 use std::ops::Deref;
 
 use darling::FromAttributes;
-use darling::FromMeta;
 use proc_macro2::Ident;
 use quote::ToTokens;
 use quote::quote;
 use syn::Generics;
 use syn::ItemTrait;
-use syn::Meta;
 use syn::Path;
 use syn::Type;
 
@@ -37,27 +35,6 @@ use crate::utils::with_index::WithIndex;
 mod others;
 mod root;
 
-#[derive(Debug, Clone)]
-pub struct InterfaceArg {
-    #[allow(dead_code)]
-    pub ident: syn::Ident,
-}
-
-impl FromMeta for InterfaceArg {
-    fn from_meta(item: &Meta) -> darling::Result<Self> {
-        match item {
-            Meta::Path(path) => {
-                let ident = path
-                    .get_ident()
-                    .ok_or_else(|| darling::Error::custom("expected identifier").with_span(path))?
-                    .clone();
-                Ok(InterfaceArg { ident })
-            }
-            _ => Err(darling::Error::custom("expected identifier").with_span(item)),
-        }
-    }
-}
-
 #[derive(FromAttributes, Debug, Clone)]
 #[darling(attributes(graphql))]
 pub struct InterfaceMethodArgAttrs {
@@ -66,6 +43,15 @@ pub struct InterfaceMethodArgAttrs {
 
     #[darling(default)]
     pub ctx: bool,
+
+    #[darling(default)]
+    pub data: bool,
+
+    #[darling(default)]
+    pub lookahead: bool,
+
+    #[darling(default)]
+    pub selection: bool,
 }
 
 impl Attributes for InterfaceMethodArgAttrs {
@@ -156,6 +142,18 @@ impl CommonArg for InterfaceMethodArg {
     fn is_marked_as_ctx(&self) -> bool {
         self.attrs.ctx
     }
+
+    fn is_marked_as_data(&self) -> bool {
+        self.attrs.data
+    }
+
+    fn is_marked_as_lookahead(&self) -> bool {
+        self.attrs.lookahead
+    }
+
+    fn is_marked_as_selection(&self) -> bool {
+        self.attrs.selection
+    }
 }
 
 impl GetArgs<InterfaceMethodArg> for InterfaceMethod {
@@ -279,13 +277,6 @@ impl CommonField for InterfaceMethod {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
-
-    fn get_args_rename_rule(&self) -> Option<&RenameRule> {
-        self.attrs
-            .rename_args
-            .as_ref()
-            .or(self.ctx.rename_args.as_ref())
-    }
 }
 
 impl ToTokens for Interface {
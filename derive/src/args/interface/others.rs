@@ -62,10 +62,6 @@ impl CommonField for OthersMethod<'_> {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.0.get_field_rename_rule()
     }
-
-    fn get_args_rename_rule(&self) -> Option<&RenameRule> {
-        self.0.get_args_rename_rule()
-    }
 }
 
 impl FieldImplementor for OthersMethod<'_> {
@@ -203,6 +199,10 @@ pub fn impl_others_register(input: &Interface) -> darling::Result<TokenStream> {
             {
                 #( #auto_registers )*
                 #define_fields
+                let registry = registry.add_implementation::<dyn #ident>(
+                    <T as #crate_name::internal::Object>::get_object_type_name().as_ref(),
+                    |value| Ok(value.try_downcast_ref::<T>()? as &dyn #ident),
+                );
                 registry.update_object(
                     <T as #crate_name::internal::Object>::get_object_type_name().as_ref(),
                     <dyn #ident as #crate_name::internal::Interface>::get_interface_type_name().as_ref(),
@@ -1,13 +1,23 @@
 pub use app::App;
+pub use complex_object_fields::ComplexObjectFields;
+pub use expand_input_object::ExpandInputObject;
+pub use expand_interface::ExpandInterface;
+pub use expand_interface_fields::ExpandInterfaceFields;
 pub use expand_object::ExpandObject;
 pub use expand_object_fields::ExpandObjectFields;
+pub use expand_union::ExpandUnion;
+pub use filter_input::FilterInput;
+pub use flatten_args::Args;
 pub use gql_enum::Enum;
+pub use graphql_error::GraphQLError;
 pub use input_object::InputObject;
 pub use interface::Interface;
 pub use mutation::Mutation;
 pub use mutation_fields::MutationFields;
 pub use mutation_root::MutationRoot;
 pub use one_of_input::OneOfInput;
+pub use order_by_input::OrderByInput;
+pub use patch_input::PatchInput;
 pub use resolved_object::ResolvedObject;
 pub use resolved_object_fields::ResolvedObjectFields;
 pub use scalar::Scalar;
@@ -16,15 +26,25 @@ pub use union::Union;
 
 mod app;
 mod common;
+mod complex_object_fields;
+mod expand_input_object;
+mod expand_interface;
+mod expand_interface_fields;
 mod expand_object;
 mod expand_object_fields;
+mod expand_union;
+mod filter_input;
+mod flatten_args;
 mod gql_enum;
+mod graphql_error;
 mod input_object;
 mod interface;
 mod mutation;
 mod mutation_fields;
 mod mutation_root;
 mod one_of_input;
+mod order_by_input;
+mod patch_input;
 mod resolved_object;
 mod resolved_object_fields;
 mod scalar;
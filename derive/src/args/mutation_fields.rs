@@ -3,16 +3,21 @@ use quote::ToTokens;
 use quote::quote;
 
 use crate::args::ExpandObjectFields;
+use crate::args::mutation_fields::relay_mutation::RelayMutation;
+use crate::args::mutation_fields::relay_mutation::expand_relay_mutations;
 use crate::utils::common::CommonArg;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::FromItemImpl;
 
-pub struct MutationFields(ExpandObjectFields);
+mod relay_mutation;
+
+pub struct MutationFields(ExpandObjectFields, Vec<RelayMutation>);
 
 impl FromItemImpl for MutationFields {
     fn from_item_impl(item: &mut syn::ItemImpl) -> darling::Result<Self> {
+        let relay_mutations = expand_relay_mutations(item)?;
         let fields = ExpandObjectFields::from_item_impl(item)?;
-        Ok(Self(fields))
+        Ok(Self(fields, relay_mutations))
     }
 }
 
@@ -49,10 +54,12 @@ fn validate_self(mutation: &MutationFields) -> darling::Result<TokenStream> {
 impl ToTokens for MutationFields {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let fields = &self.0;
+        let relay_mutations = &self.1;
         let validate_self = validate_self(self).unwrap();
         tokens.extend(quote! {
             #validate_self
             #fields
+            #(#relay_mutations)*
         })
     }
 }
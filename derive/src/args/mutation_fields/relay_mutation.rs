@@ -0,0 +1,317 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::format_ident;
+use quote::quote;
+use syn::Meta;
+use syn::Token;
+use syn::parse_quote;
+use syn::punctuated::Punctuated;
+
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::rename_rule::RenameRuleExt;
+use crate::utils::rename_rule::RenameTarget;
+use crate::utils::rename_rule::calc_field_name;
+use crate::utils::type_utils::get_option_inner_type;
+use crate::utils::type_utils::get_owned_type;
+use crate::utils::type_utils::is_type_ref;
+
+/// attributes only used to expand relay mutations, removed before the method is parsed as a field
+const RELAY_ATTRIBUTES: &[&str] = &["relay_mutation", "payload_field"];
+
+#[derive(FromAttributes, Debug, Clone, Default)]
+#[darling(attributes(graphql), allow_unknown_fields)]
+struct RelayMutationImplAttrs {
+    #[darling(default)]
+    rename_fields: Option<RenameRule>,
+
+    #[darling(default)]
+    rename_args: Option<String>,
+}
+
+#[derive(FromAttributes, Debug, Clone, Default)]
+#[darling(attributes(graphql), allow_unknown_fields)]
+struct RelayMutationMethodAttrs {
+    #[darling(default)]
+    relay_mutation: bool,
+
+    #[darling(default)]
+    payload_field: Option<String>,
+
+    #[darling(default)]
+    name: Option<String>,
+
+    #[darling(default)]
+    rename_args: Option<String>,
+}
+
+#[derive(FromAttributes, Debug, Clone, Default)]
+#[darling(attributes(graphql), allow_unknown_fields)]
+struct RelayMutationArgAttrs {
+    #[darling(default)]
+    ctx: bool,
+
+    #[darling(default)]
+    data: bool,
+
+    #[darling(default)]
+    lookahead: bool,
+
+    #[darling(default)]
+    selection: bool,
+}
+
+/// The `<Name>Input` and `<Name>Payload` types of a `#[graphql(relay_mutation)]` method
+pub struct RelayMutation {
+    input: TokenStream,
+    payload: TokenStream,
+}
+
+impl ToTokens for RelayMutation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.input.to_tokens(tokens);
+        self.payload.to_tokens(tokens);
+    }
+}
+
+/// Replace every `#[graphql(relay_mutation)]` method with a wrapper taking a single `input`
+/// argument and returning the payload, the original method is kept as a skipped field.
+pub fn expand_relay_mutations(item: &mut syn::ItemImpl) -> darling::Result<Vec<RelayMutation>> {
+    let impl_attrs = RelayMutationImplAttrs::from_attributes(&item.attrs)?;
+    let mut relay_mutations = Vec::new();
+    let mut hidden_methods = Vec::new();
+    for impl_item in item.items.iter_mut() {
+        let syn::ImplItem::Fn(method) = impl_item else {
+            continue;
+        };
+        let attrs = RelayMutationMethodAttrs::from_attributes(&method.attrs)?;
+        if !attrs.relay_mutation {
+            strip_relay_attributes(&mut method.attrs)?;
+            continue;
+        }
+        // the wrapper takes the place of the method to keep the field order
+        let mut hidden_method = method.clone();
+        let (relay_mutation, wrapper) =
+            expand_relay_mutation(&impl_attrs, &attrs, &mut hidden_method)?;
+        relay_mutations.push(relay_mutation);
+        *method = wrapper;
+        hidden_methods.push(syn::ImplItem::Fn(hidden_method));
+    }
+    item.items.extend(hidden_methods);
+    Ok(relay_mutations)
+}
+
+fn strip_relay_attributes(attrs: &mut Vec<syn::Attribute>) -> darling::Result<()> {
+    let mut stripped = Vec::new();
+    for attr in attrs.drain(..) {
+        if !attr.path().is_ident("graphql") {
+            stripped.push(attr);
+            continue;
+        }
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        let metas: Vec<_> = metas
+            .into_iter()
+            .filter(|meta| {
+                !RELAY_ATTRIBUTES
+                    .iter()
+                    .any(|name| meta.path().is_ident(name))
+            })
+            .collect();
+        if !metas.is_empty() {
+            stripped.push(parse_quote!(#[graphql(#(#metas),*)]));
+        }
+    }
+    *attrs = stripped;
+    Ok(())
+}
+
+/// arguments filled by the resolver stay on the wrapper instead of becoming input fields
+fn is_ctx_arg(arg: &syn::PatType) -> darling::Result<bool> {
+    let attrs = RelayMutationArgAttrs::from_attributes(&arg.attrs)?;
+    let is_ctx_ident =
+        matches!(&*arg.pat, syn::Pat::Ident(pat) if pat.ident == "ctx" || pat.ident == "_ctx");
+    Ok(attrs.ctx || attrs.data || attrs.lookahead || attrs.selection || is_ctx_ident)
+}
+
+fn get_arg_ident(arg: &syn::PatType) -> darling::Result<&syn::Ident> {
+    match &*arg.pat {
+        syn::Pat::Ident(pat) => Ok(&pat.ident),
+        pat => Err(
+            darling::Error::custom("relay mutation arguments must be identifiers").with_span(pat),
+        ),
+    }
+}
+
+/// `T` for `Result<T>` and `Result<T, E>` return types
+fn get_result_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(inner)) => Some(inner),
+        _ => None,
+    }
+}
+
+fn get_payload_field_ident(
+    attrs: &RelayMutationMethodAttrs,
+    value_type: &syn::Type,
+    method: &syn::ImplItemFn,
+) -> darling::Result<syn::Ident> {
+    if let Some(payload_field) = &attrs.payload_field {
+        return Ok(format_ident!("{}", payload_field));
+    }
+    let ty = get_option_inner_type(value_type).unwrap_or(value_type);
+    match ty {
+        syn::Type::Path(type_path)
+            if type_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.arguments.is_empty()) =>
+        {
+            let ident = &type_path.path.segments.last().unwrap().ident;
+            let name = Some(RenameRule::Snake).rename(ident.to_string(), RenameTarget::Field);
+            Ok(format_ident!("{}", name))
+        }
+        _ => Err(darling::Error::custom(
+            "cannot name the payload field from the return type, set `payload_field`",
+        )
+        .with_span(&method.sig.ident)),
+    }
+}
+
+fn expand_relay_mutation(
+    impl_attrs: &RelayMutationImplAttrs,
+    attrs: &RelayMutationMethodAttrs,
+    method: &mut syn::ImplItemFn,
+) -> darling::Result<(RelayMutation, syn::ImplItemFn)> {
+    let crate_name = get_crate_name();
+    let method_ident = method.sig.ident.clone();
+    let field_name = calc_field_name(
+        attrs.name.as_deref(),
+        &method_ident.to_string(),
+        impl_attrs.rename_fields.as_ref(),
+    );
+    let type_name = RenameTarget::Type.rename(&field_name);
+    let input_name = format!("{}Input", type_name);
+    let payload_name = format!("{}Payload", type_name);
+    let input_ident = format_ident!("{}", input_name);
+    let payload_ident = format_ident!("{}", payload_name);
+    let relay_ident = format_ident!("__relay_{}", method_ident);
+
+    let mut wrapper_args = Vec::new();
+    let mut input_fields = Vec::new();
+    let mut input_idents = Vec::new();
+    let mut call_args = Vec::new();
+    for arg in &method.sig.inputs {
+        let arg = match arg {
+            syn::FnArg::Receiver(receiver) => {
+                return Err(darling::Error::custom(
+                    "Mutation methods must not have a self argument",
+                )
+                .with_span(receiver));
+            }
+            syn::FnArg::Typed(arg) => arg,
+        };
+        let ident = get_arg_ident(arg)?;
+        if is_ctx_arg(arg)? {
+            wrapper_args.push(quote!(#arg));
+            call_args.push(quote!(#ident));
+            continue;
+        }
+        let arg_attrs = arg
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("graphql"));
+        let ty = get_owned_type(&arg.ty);
+        input_fields.push(quote! {
+            #(#arg_attrs)*
+            #ident: #ty,
+        });
+        input_idents.push(ident.clone());
+        if is_type_ref(&arg.ty) {
+            call_args.push(quote!(&#ident));
+        } else {
+            call_args.push(quote!(#ident));
+        }
+    }
+
+    let output_type = match &method.sig.output {
+        syn::ReturnType::Type(_, ty) => ty.as_ref(),
+        syn::ReturnType::Default => {
+            return Err(
+                darling::Error::custom("Field must have return type").with_span(&method_ident)
+            );
+        }
+    };
+    let result_inner_type = get_result_inner_type(output_type);
+    let value_type = result_inner_type.unwrap_or(output_type);
+    let payload_field_ident = get_payload_field_ident(attrs, value_type, method)?;
+
+    // the arguments become the fields of the input object
+    let rename_args = attrs
+        .rename_args
+        .as_ref()
+        .or(impl_attrs.rename_args.as_ref())
+        .map(|rule| quote!(rename_fields = #rule,));
+    let input = quote! {
+        #[derive(#crate_name::InputObject)]
+        #[graphql(name = #input_name, #rename_args)]
+        struct #input_ident {
+            client_mutation_id: Option<String>,
+            #(#input_fields)*
+        }
+    };
+    let payload = quote! {
+        #[derive(#crate_name::SimpleObject)]
+        #[graphql(name = #payload_name)]
+        struct #payload_ident {
+            client_mutation_id: Option<String>,
+            #payload_field_ident: #value_type,
+        }
+    };
+
+    let asyncness = &method.sig.asyncness;
+    let await_code = asyncness.map(|_| quote!(.await));
+    let (return_type, try_code, payload_code) = if result_inner_type.is_some() {
+        (
+            quote!(#crate_name::Result<#payload_ident>),
+            quote!(?),
+            quote!(Ok(payload)),
+        )
+    } else {
+        (quote!(#payload_ident), quote!(), quote!(payload))
+    };
+
+    let mut wrapper_attrs = method.attrs.clone();
+    strip_relay_attributes(&mut wrapper_attrs)?;
+    let wrapper: syn::ImplItemFn = parse_quote! {
+        #(#wrapper_attrs)*
+        #asyncness fn #method_ident(#(#wrapper_args,)* input: #input_ident) -> #return_type {
+            let #input_ident { client_mutation_id, #(#input_idents,)* } = input;
+            let value = Self::#relay_ident(#(#call_args),*)#await_code #try_code;
+            let payload = #payload_ident {
+                client_mutation_id,
+                #payload_field_ident: value,
+            };
+            #payload_code
+        }
+    };
+
+    method.sig.ident = relay_ident;
+    method
+        .attrs
+        .retain(|attr| !attr.path().is_ident("graphql") && !attr.path().is_ident("doc"));
+    method.attrs.push(parse_quote!(#[graphql(skip)]));
+
+    Ok((RelayMutation { input, payload }, wrapper))
+}
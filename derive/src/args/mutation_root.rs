@@ -56,10 +56,13 @@ impl From<MutationRoot> for SimpleObject {
                     registers,
                     marks: vec![],
                     impls: vec![],
+                    complex: false,
+                    input_name: None,
                 },
             },
             inner: BaseStruct {
                 ident,
+                vis: syn::Visibility::Inherited,
                 generics: Generics::default(),
                 data: Fields::new(Style::Unit, vec![]),
             },
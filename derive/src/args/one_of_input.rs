@@ -1,4 +1,5 @@
 use darling::FromAttributes;
+use darling::ast::Style;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::quote;
@@ -13,7 +14,8 @@ use crate::utils::common::GetArgs;
 use crate::utils::common::GetFields;
 use crate::utils::crate_name::get_crate_name;
 use crate::utils::derive_types::BaseEnum;
-use crate::utils::derive_types::NewtypeVariant;
+use crate::utils::derive_types::BaseField;
+use crate::utils::derive_types::BaseVariant;
 use crate::utils::error::IntoTokenStream;
 use crate::utils::macros::*;
 use crate::utils::register_attr::RegisterAttr;
@@ -38,14 +40,51 @@ pub struct OneOfInputFieldContext {
     pub rename_fields: Option<RenameRule>,
 }
 
+from_field!(
+    OneOfInputVariantField,
+    WithAttributes<WithDoc<OneOfInputFieldAttrs>, WithContext<OneOfInputFieldContext, BaseField>>,
+);
+
 from_variant!(
     OneOfInputField,
     WithAttributes<
         WithDoc<OneOfInputFieldAttrs>,
-        WithContext<OneOfInputFieldContext, NewtypeVariant>,
+        WithContext<OneOfInputFieldContext, BaseVariant<OneOfInputVariantField>>,
     >,
+    inner = fields,
 );
 
+impl MakeContext<OneOfInputFieldContext> for OneOfInputField {
+    fn make_context(&self) -> OneOfInputFieldContext {
+        self.ctx.clone()
+    }
+}
+
+/// The shape of a oneof variant
+enum VariantKind<'a> {
+    /// `Latest`, a `Boolean` field that only accepts `true`
+    Unit,
+    /// `Name(String)`, a field of the inner type
+    Newtype(&'a syn::Type),
+    /// `ByRange { from: i32, to: i32 }`, a field of a generated nested input object
+    Struct(&'a [OneOfInputVariantField]),
+}
+
+impl OneOfInputField {
+    fn get_kind(&self) -> darling::Result<VariantKind<'_>> {
+        match self.fields.style {
+            Style::Unit => Ok(VariantKind::Unit),
+            Style::Struct => Ok(VariantKind::Struct(&self.fields.fields)),
+            Style::Tuple if self.fields.len() == 1 => {
+                Ok(VariantKind::Newtype(&self.fields.fields[0].ty))
+            }
+            Style::Tuple => {
+                Err(darling::Error::unsupported_shape("tuple variant").with_span(&self.ident))
+            }
+        }
+    }
+}
+
 #[derive(FromAttributes, Debug, Clone)]
 #[darling(attributes(graphql))]
 pub struct OneOfInputAttrs {
@@ -114,7 +153,41 @@ impl CommonField for OneOfInputField {
     }
 
     fn get_type(&self) -> darling::Result<&syn::Type> {
-        Ok(&self.fields.ty)
+        match self.get_kind()? {
+            VariantKind::Newtype(ty) => Ok(ty),
+            _ => {
+                Err(darling::Error::custom("only newtype variants have a type")
+                    .with_span(&self.ident))
+            }
+        }
+    }
+
+    fn get_skip(&self) -> bool {
+        self.attrs.skip
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_fields.as_ref()
+    }
+}
+
+impl CommonField for OneOfInputVariantField {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn get_ident(&self) -> darling::Result<&syn::Ident> {
+        self.ident
+            .as_ref()
+            .ok_or_else(|| darling::Error::unsupported_shape("unnamed field").with_span(&self.ty))
+    }
+
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        Ok(&self.ty)
     }
 
     fn get_skip(&self) -> bool {
@@ -142,9 +215,52 @@ impl GetArgs<()> for OneOfInputField {
     }
 }
 
-fn get_define_field(field: &impl CommonField) -> darling::Result<TokenStream> {
+fn get_nested_object_name_code(field: &OneOfInputField) -> TokenStream {
+    let crate_name = get_crate_name();
+    let variant_name = field.ident.to_string();
+    quote! {
+        let __name = <Self as #crate_name::internal::InputObject>::get_input_object_type_name();
+        let __nested_name = format!("{}{}Input", __name.strip_suffix("Input").unwrap_or(&__name), #variant_name);
+    }
+}
+
+fn get_define_nested_field(field: &OneOfInputVariantField) -> darling::Result<TokenStream> {
     let description = common::field_description(field)?;
-    let get_new_input_value_code = common::get_new_optional_input_value_code(field)?;
+    let get_new_input_value_code = common::get_new_input_value_code(field)?;
+    Ok(quote! {
+        #get_new_input_value_code
+        #description
+        let __nested = __nested.field(field);
+    })
+}
+
+fn get_define_field(field: &OneOfInputField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let description = common::field_description(field)?;
+    let get_new_input_value_code = match field.get_kind()? {
+        VariantKind::Unit => {
+            let field_name = common::get_input_field_name(field)?;
+            quote! {
+                let field = #crate_name::dynamic::InputValue::new(#field_name, #crate_name::dynamic::TypeRef::named(#crate_name::dynamic::TypeRef::BOOLEAN));
+            }
+        }
+        VariantKind::Newtype(_) => common::get_new_optional_input_value_code(field)?,
+        VariantKind::Struct(fields) => {
+            let field_name = common::get_input_field_name(field)?;
+            let nested_object_name = get_nested_object_name_code(field);
+            let define_nested_fields = fields
+                .iter()
+                .filter(|field| !field.get_skip())
+                .map(|field| get_define_nested_field(field).into_token_stream());
+            quote! {
+                #nested_object_name
+                let __nested = #crate_name::dynamic::InputObject::new(&__nested_name);
+                #(#define_nested_fields)*
+                let registry = registry.register_type(__nested);
+                let field = #crate_name::dynamic::InputValue::new(#field_name, #crate_name::dynamic::TypeRef::named(&__nested_name));
+            }
+        }
+    };
     Ok(quote! {
         #get_new_input_value_code
         #description
@@ -152,11 +268,7 @@ fn get_define_field(field: &impl CommonField) -> darling::Result<TokenStream> {
     })
 }
 
-fn get_define_fields<O, F>(object: &O) -> darling::Result<TokenStream>
-where
-    O: GetFields<F>,
-    F: CommonField,
-{
+fn get_define_fields(object: &OneOfInput) -> darling::Result<TokenStream> {
     Ok(object
         .get_fields()?
         .iter()
@@ -165,20 +277,52 @@ where
         .collect())
 }
 
+fn get_nested_type_register_code(object: &OneOfInput) -> darling::Result<TokenStream> {
+    let mut types: Vec<&syn::Type> = Vec::new();
+    for field in object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+    {
+        let field_types: Vec<&syn::Type> = match field.get_kind()? {
+            VariantKind::Unit => vec![],
+            VariantKind::Newtype(ty) => vec![ty],
+            VariantKind::Struct(fields) => fields
+                .iter()
+                .filter(|field| !field.get_skip())
+                .map(|field| &field.ty)
+                .collect(),
+        };
+        for ty in field_types {
+            if !types.contains(&ty) {
+                types.push(ty);
+            }
+        }
+    }
+    let codes = types.into_iter().map(|ty| {
+        let ty = common::replace_type_generics_with_static(ty);
+        quote! {
+            let registry = registry.register::<#ty>();
+        }
+    });
+    Ok(quote!(#(#codes)*))
+}
+
 fn impl_register(object: &OneOfInput) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = &object.ident;
-    let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
+    let register_nested_types = get_nested_type_register_code(object).into_token_stream();
 
     let define_object = common::impl_define_input_object();
     let define_fields = get_define_fields(object)?;
     let description = common::object_description(object.get_doc()?.as_deref())?;
     let register_object_code = common::register_object_code();
 
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
     let register_attr = &object.attrs.registers;
 
     Ok(quote! {
-        impl #crate_name::internal::Register for #ident {
+        impl #impl_generics #crate_name::internal::Register for #ident #ty_generics #where_clause {
             fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
 
                 #( #register_attr )*
@@ -202,24 +346,80 @@ fn get_item_ident(index: usize, ident: &syn::Ident) -> syn::Ident {
     syn::Ident::new(&format!("field{}", index), ident.span())
 }
 
-fn get_field_usage(index: usize, field: &impl CommonField) -> darling::Result<TokenStream> {
+fn get_nested_field_value(
+    index: usize,
+    field: &OneOfInputVariantField,
+) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let field_ident = field.get_ident()?;
-    let field_name = common::get_input_field_name(field)?;
     let item = get_item_ident(index, field_ident);
+    if field.get_skip() {
+        return Ok(quote! {
+            let #item = Default::default();
+        });
+    }
+    let field_name = common::get_input_field_name(field)?;
     Ok(quote! {
-        if let Some(__field) = __object.get(#field_name) {
+        let #item = #crate_name::internal::FromValue::from_value(__nested.try_get(#field_name)).map_err(|e| e.into_field_error(#field_name))?;
+    })
+}
+
+fn get_field_usage(index: usize, field: &OneOfInputField) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let field_ident = field.get_ident()?;
+    let field_name = common::get_input_field_name(field)?;
+    let item = get_item_ident(index, field_ident);
+    let construct = match field.get_kind()? {
+        VariantKind::Unit => quote! {
+            let #item: bool = #crate_name::internal::FromValue::from_value(Ok(__field)).map_err(|e| e.into_field_error(#field_name))?;
+            if !#item {
+                return Err(#crate_name::internal::InputValueError::custom(format!("Only `true` is accepted for field \"{}\"", #field_name)));
+            }
+            return Ok(Self::#field_ident);
+        },
+        VariantKind::Newtype(_) => quote! {
             let #item = #crate_name::internal::FromValue::from_value(Ok(__field)).map_err(|e| e.into_field_error(#field_name))?;
             return Ok(Self::#field_ident(#item));
+        },
+        VariantKind::Struct(fields) => {
+            let values = fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| get_nested_field_value(index, field).into_token_stream());
+            let usages = fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| get_nested_field_usage(index, field).into_token_stream());
+            quote! {
+                let __nested = __field.object().map_err(|e| {
+                    #crate_name::internal::InputValueError::<Self>::from(e).into_field_error(#field_name)
+                })?;
+                #(#values)*
+                return Ok(Self::#field_ident {
+                    #(#usages)*
+                });
+            }
+        }
+    };
+    Ok(quote! {
+        if let Some(__field) = __object.get(#field_name) {
+            #construct
         }
     })
 }
 
-fn get_fields_usage<O, F>(object: &O) -> darling::Result<TokenStream>
-where
-    O: GetFields<F>,
-    F: CommonField,
-{
+fn get_nested_field_usage(
+    index: usize,
+    field: &OneOfInputVariantField,
+) -> darling::Result<TokenStream> {
+    let field_ident = field.get_ident()?;
+    let item = get_item_ident(index, field_ident);
+    Ok(quote! {
+        #field_ident: #item,
+    })
+}
+
+fn get_fields_usage(object: &OneOfInput) -> darling::Result<TokenStream> {
     let items: Vec<_> = object
         .get_fields()?
         .iter()
@@ -243,10 +443,11 @@ fn no_field_error() -> TokenStream {
 fn impl_from_value(object: &OneOfInput) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let ident = object.get_ident();
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
     let fields_usage = get_fields_usage(object)?;
     let no_field_error = no_field_error();
     Ok(quote!(
-        impl #crate_name::internal::FromValue for #ident {
+        impl #impl_generics #crate_name::internal::FromValue for #ident #ty_generics #where_clause {
             fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
                 let __value = __value?;
                 let __object = __value.object()?;
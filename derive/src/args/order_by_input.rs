@@ -0,0 +1,305 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::format_ident;
+use quote::quote;
+use syn::Generics;
+
+use crate::args::common;
+use crate::utils::common::CommonField;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseStruct;
+use crate::utils::derive_types::NamedField;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::rename_rule::RenameTarget;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::WithContext;
+use crate::utils::with_doc::WithDoc;
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct OrderByInputFieldAttrs {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct OrderByInputFieldContext {
+    pub rename_items: Option<RenameRule>,
+}
+
+from_field!(
+    OrderByInputField,
+    WithAttributes<
+        WithDoc<OrderByInputFieldAttrs>,
+        WithContext<OrderByInputFieldContext, NamedField>,
+    >,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql))]
+pub struct OrderByInputAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub rename_items: Option<RenameRule>,
+}
+
+from_derive_input!(
+    OrderByInput,
+    WithAttributes<OrderByInputAttrs, BaseStruct<OrderByInputField, Generics>>,
+    ctx,
+);
+
+impl MakeContext<OrderByInputFieldContext> for OrderByInput {
+    fn make_context(&self) -> OrderByInputFieldContext {
+        OrderByInputFieldContext {
+            rename_items: self.attrs.rename_items,
+        }
+    }
+}
+
+impl CommonField for OrderByInputField {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn get_ident(&self) -> darling::Result<&syn::Ident> {
+        Ok(&self.ident)
+    }
+
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        Ok(&self.ty)
+    }
+
+    fn get_skip(&self) -> bool {
+        self.attrs.skip
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_items.as_ref()
+    }
+}
+
+impl GetFields<OrderByInputField> for OrderByInput {
+    fn get_fields(&self) -> darling::Result<&Vec<OrderByInputField>> {
+        Ok(&self.data.fields)
+    }
+}
+
+fn get_base_name(object: &OrderByInput) -> String {
+    object
+        .attrs
+        .name
+        .clone()
+        .unwrap_or_else(|| object.ident.to_string())
+}
+
+fn get_order_by_ident(object: &OrderByInput) -> syn::Ident {
+    format_ident!("{}OrderBy", object.ident)
+}
+
+fn get_order_field_ident(object: &OrderByInput) -> syn::Ident {
+    format_ident!("{}OrderField", object.ident)
+}
+
+fn get_variant_ident(field: &OrderByInputField) -> syn::Ident {
+    let name = field.ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    syn::Ident::new(&RenameTarget::Type.rename(name), field.ident.span())
+}
+
+fn get_sortable_fields(object: &OrderByInput) -> darling::Result<Vec<&OrderByInputField>> {
+    if !object.generics.params.is_empty() {
+        return Err(
+            darling::Error::custom("OrderByInput does not support generics")
+                .with_span(&object.generics),
+        );
+    }
+    let fields: Vec<_> = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip())
+        .collect();
+    if fields.is_empty() {
+        return Err(
+            darling::Error::custom("OrderByInput requires at least one sortable field")
+                .with_span(&object.ident),
+        );
+    }
+    Ok(fields)
+}
+
+fn define_order_field_enum(object: &OrderByInput) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let vis = &object.vis;
+    let ident = &object.ident;
+    let order_field_ident = get_order_field_ident(object);
+    let type_name = format!("{}OrderField", get_base_name(object));
+    let doc = format!("Sortable fields of [`{}`]", ident);
+    let fields = get_sortable_fields(object)?;
+
+    let mut variants = Vec::new();
+    let mut define_items = Vec::new();
+    let mut from_value_arms = Vec::new();
+    let mut compare_arms = Vec::new();
+    for field in fields {
+        let variant = get_variant_ident(field);
+        let field_ident = &field.ident;
+        let item_name = common::get_enum_item_name(field)?;
+        let description = common::field_description(field)?;
+        variants.push(quote!(#variant,));
+        define_items.push(quote! {
+            let field = #crate_name::dynamic::EnumItem::new(#item_name);
+            #description
+            let object = object.item(field);
+        });
+        from_value_arms.push(quote! {
+            #item_name => Ok(#order_field_ident::#variant),
+        });
+        compare_arms.push(quote! {
+            #order_field_ident::#variant => #crate_name::internal::compare_order_values(&a.#field_ident, &b.#field_ident),
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #order_field_ident {
+            #(#variants)*
+        }
+
+        impl #crate_name::internal::TypeName for #order_field_ident {
+            fn get_type_name() -> std::borrow::Cow<'static, str> {
+                #type_name.into()
+            }
+        }
+        impl #crate_name::internal::InputTypeName for #order_field_ident {}
+
+        impl #crate_name::internal::Register for #order_field_ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                let object = #crate_name::dynamic::Enum::new(
+                    <Self as #crate_name::internal::InputTypeName>::get_input_type_name().as_ref(),
+                );
+                #(#define_items)*
+                registry.register_type(object)
+            }
+        }
+
+        impl #crate_name::internal::FromValue for #order_field_ident {
+            fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
+                let __value = __value?;
+                let string_value = __value.enum_name()?;
+                match string_value {
+                    #(#from_value_arms)*
+                    _ => Err(#crate_name::internal::InputValueError::custom(format!(
+                        "Unknown variant `{}` for enum `{}`",
+                        string_value,
+                        <Self as #crate_name::internal::InputTypeName>::get_input_type_name().as_ref()
+                    ))),
+                }
+            }
+        }
+
+        impl #crate_name::OrderBy<#ident> for #order_field_ident {
+            fn compare(&self, a: &#ident, b: &#ident) -> std::cmp::Ordering {
+                match self {
+                    #(#compare_arms)*
+                }
+            }
+        }
+    })
+}
+
+fn define_order_by_input(object: &OrderByInput) -> TokenStream {
+    let crate_name = get_crate_name();
+    let vis = &object.vis;
+    let ident = &object.ident;
+    let order_by_ident = get_order_by_ident(object);
+    let order_field_ident = get_order_field_ident(object);
+    let type_name = format!("{}OrderBy", get_base_name(object));
+    let doc = format!("An order by key for lists of [`{}`]", ident);
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis struct #order_by_ident {
+            #vis field: #order_field_ident,
+            #vis direction: #crate_name::SortDirection,
+        }
+
+        impl #crate_name::internal::TypeName for #order_by_ident {
+            fn get_type_name() -> std::borrow::Cow<'static, str> {
+                #type_name.into()
+            }
+        }
+        impl #crate_name::internal::InputTypeName for #order_by_ident {}
+        impl #crate_name::internal::InputObject for #order_by_ident {}
+
+        impl #crate_name::internal::Register for #order_by_ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                let registry = registry.register::<#order_field_ident>();
+                let registry = registry.register::<#crate_name::SortDirection>();
+
+                let object = #crate_name::dynamic::InputObject::new(
+                    <Self as #crate_name::internal::InputObject>::get_input_object_type_name().as_ref(),
+                );
+                let object = object.field(#crate_name::dynamic::InputValue::new(
+                    "field",
+                    <#order_field_ident as #crate_name::internal::GetInputTypeRef>::get_input_type_ref(),
+                ));
+                let object = object.field(
+                    #crate_name::dynamic::InputValue::new(
+                        "direction",
+                        <Option<#crate_name::SortDirection> as #crate_name::internal::GetInputTypeRef>::get_input_type_ref(),
+                    )
+                    .default_value(#crate_name::SortDirection::Asc),
+                );
+                registry.register_type(object)
+            }
+        }
+
+        impl #crate_name::internal::FromValue for #order_by_ident {
+            fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
+                let __value = __value?;
+                let __object = __value.object()?;
+                let field = #crate_name::internal::FromValue::from_value(__object.try_get("field")).map_err(|e| e.into_field_error("field"))?;
+                let direction: Option<#crate_name::SortDirection> = #crate_name::internal::FromValue::from_value(__object.try_get("direction")).map_err(|e| e.into_field_error("direction"))?;
+                Ok(Self {
+                    field,
+                    direction: direction.unwrap_or_default(),
+                })
+            }
+        }
+
+        impl #crate_name::OrderBy<#ident> for #order_by_ident {
+            fn compare(&self, a: &#ident, b: &#ident) -> std::cmp::Ordering {
+                self.direction.apply(#crate_name::OrderBy::compare(&self.field, a, b))
+            }
+        }
+    }
+}
+
+impl ToTokens for OrderByInput {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let define_order_field_enum = define_order_field_enum(self).into_token_stream();
+        let define_order_by_input = define_order_by_input(self);
+        tokens.extend(quote! {
+            #define_order_field_enum
+            #define_order_by_input
+        });
+    }
+}
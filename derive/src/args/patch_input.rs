@@ -0,0 +1,290 @@
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::format_ident;
+use quote::quote;
+use syn::Generics;
+
+use crate::args::common;
+use crate::utils::common::CommonField;
+use crate::utils::common::GetFields;
+use crate::utils::crate_name::get_crate_name;
+use crate::utils::derive_types::BaseStruct;
+use crate::utils::derive_types::NamedField;
+use crate::utils::error::IntoTokenStream;
+use crate::utils::macros::*;
+use crate::utils::rename_rule::RenameRule;
+use crate::utils::rename_rule::calc_type_name;
+use crate::utils::type_utils::get_option_inner_type;
+use crate::utils::with_attributes::WithAttributes;
+use crate::utils::with_context::MakeContext;
+use crate::utils::with_context::WithContext;
+use crate::utils::with_doc::WithDoc;
+
+// unknown fields are allowed, the model struct usually derives other types too
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql), allow_unknown_fields)]
+pub struct PatchInputFieldAttrs {
+    #[darling(default)]
+    pub skip: bool,
+
+    #[darling(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct PatchInputFieldContext {
+    pub rename_fields: Option<RenameRule>,
+}
+
+from_field!(
+    PatchInputField,
+    WithAttributes<WithDoc<PatchInputFieldAttrs>, WithContext<PatchInputFieldContext, NamedField>>,
+);
+
+#[derive(FromAttributes, Debug, Clone)]
+#[darling(attributes(graphql), allow_unknown_fields)]
+pub struct PatchInputAttrs {
+    #[darling(default)]
+    pub name: Option<String>,
+
+    #[darling(default)]
+    pub rename_fields: Option<RenameRule>,
+}
+
+from_derive_input!(
+    PatchInput,
+    WithAttributes<PatchInputAttrs, BaseStruct<PatchInputField, Generics>>,
+    ctx,
+);
+
+impl MakeContext<PatchInputFieldContext> for PatchInput {
+    fn make_context(&self) -> PatchInputFieldContext {
+        PatchInputFieldContext {
+            rename_fields: self.attrs.rename_fields,
+        }
+    }
+}
+
+impl CommonField for PatchInputField {
+    fn get_name(&self) -> Option<&str> {
+        self.attrs.name.as_deref()
+    }
+
+    fn get_ident(&self) -> darling::Result<&syn::Ident> {
+        Ok(&self.ident)
+    }
+
+    fn get_type(&self) -> darling::Result<&syn::Type> {
+        Ok(&self.ty)
+    }
+
+    fn get_skip(&self) -> bool {
+        self.attrs.skip
+    }
+
+    fn get_doc(&self) -> darling::Result<Option<String>> {
+        Ok(self.attrs.doc.clone())
+    }
+
+    fn get_field_rename_rule(&self) -> Option<&RenameRule> {
+        self.ctx.rename_fields.as_ref()
+    }
+}
+
+impl GetFields<PatchInputField> for PatchInput {
+    fn get_fields(&self) -> darling::Result<&Vec<PatchInputField>> {
+        Ok(&self.data.fields)
+    }
+}
+
+fn get_patch_ident(object: &PatchInput) -> syn::Ident {
+    format_ident!("{}Patch", object.ident)
+}
+
+fn get_patch_name(object: &PatchInput) -> String {
+    let name = object.attrs.name.as_deref();
+    format!("{}Patch", calc_type_name(name, &object.ident.to_string()))
+}
+
+fn get_patch_fields(object: &PatchInput) -> darling::Result<Vec<&PatchInputField>> {
+    if !object.generics.params.is_empty() {
+        return Err(
+            darling::Error::custom("PatchInput does not support generics")
+                .with_span(&object.generics),
+        );
+    }
+    Ok(object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.get_skip())
+        .collect())
+}
+
+/// The type inside `MaybeUndefined`, `Option` fields are cleared by `null`
+fn get_value_type(field: &PatchInputField) -> &syn::Type {
+    get_option_inner_type(&field.ty).unwrap_or(&field.ty)
+}
+
+fn define_patch_struct(object: &PatchInput) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let vis = &object.vis;
+    let patch_ident = get_patch_ident(object);
+    let doc = format!("A partial update of [`{}`]", object.ident);
+    let fields = get_patch_fields(object)?.into_iter().map(|field| {
+        let ident = &field.ident;
+        let ty = get_value_type(field);
+        quote!(#vis #ident: #crate_name::MaybeUndefined<#ty>,)
+    });
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Default)]
+        #vis struct #patch_ident {
+            #(#fields)*
+        }
+    })
+}
+
+fn impl_input_object(object: &PatchInput) -> TokenStream {
+    let crate_name = get_crate_name();
+    let patch_ident = get_patch_ident(object);
+    let name = get_patch_name(object);
+    quote! {
+        impl #crate_name::internal::TypeName for #patch_ident {
+            fn get_type_name() -> std::borrow::Cow<'static, str> {
+                #name.into()
+            }
+        }
+        impl #crate_name::internal::InputTypeName for #patch_ident {}
+        impl #crate_name::internal::InputObject for #patch_ident {}
+    }
+}
+
+fn impl_register(object: &PatchInput) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let patch_ident = get_patch_ident(object);
+    let define_object = common::impl_define_input_object();
+    let register_object_code = common::register_object_code();
+
+    let mut register_types = Vec::new();
+    let mut define_fields = Vec::new();
+    for field in get_patch_fields(object)? {
+        let ty = get_value_type(field);
+        let field_name = common::get_input_field_name(field)?;
+        let field_description = common::field_description(field)?;
+        register_types.push(quote! {
+            let registry = registry.register::<#ty>();
+        });
+        define_fields.push(quote! {
+            let field = #crate_name::dynamic::InputValue::new(
+                #field_name,
+                <#crate_name::MaybeUndefined<#ty> as #crate_name::internal::GetInputTypeRef>::get_input_type_ref(),
+            );
+            #field_description
+            let object = object.field(field);
+        });
+    }
+
+    Ok(quote! {
+        impl #crate_name::internal::Register for #patch_ident {
+            fn register(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #(#register_types)*
+
+                #define_object
+
+                #(#define_fields)*
+
+                #register_object_code
+            }
+        }
+    })
+}
+
+fn impl_from_value(object: &PatchInput) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let patch_ident = get_patch_ident(object);
+    let mut fields_value = Vec::new();
+    let mut fields_usage = Vec::new();
+    for field in get_patch_fields(object)? {
+        let ident = &field.ident;
+        let ty = get_value_type(field);
+        let field_name = common::get_input_field_name(field)?;
+        fields_value.push(quote! {
+            let #ident: #crate_name::MaybeUndefined<#ty> = #crate_name::internal::FromValue::from_value(__object.try_get(#field_name)).map_err(|e| e.into_field_error(#field_name))?;
+        });
+        if get_option_inner_type(&field.ty).is_none() {
+            fields_value.push(quote! {
+                if #ident.is_null() {
+                    return Err(#crate_name::internal::InputValueError::<#ty>::new(
+                        "null is not allowed for a non-optional field".to_string(),
+                    )
+                    .into_field_error(#field_name)
+                    .into());
+                }
+            });
+        }
+        fields_usage.push(quote!(#ident,));
+    }
+    Ok(quote! {
+        impl #crate_name::internal::FromValue for #patch_ident {
+            fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
+                let __value = __value?;
+                let __object = __value.object()?;
+                #(#fields_value)*
+                Ok(Self {
+                    #(#fields_usage)*
+                })
+            }
+        }
+    })
+}
+
+fn impl_apply(object: &PatchInput) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let patch_ident = get_patch_ident(object);
+    let apply_fields = get_patch_fields(object)?.into_iter().map(|field| {
+        let field_ident = &field.ident;
+        if get_option_inner_type(&field.ty).is_some() {
+            quote! {
+                match &self.#field_ident {
+                    #crate_name::MaybeUndefined::Value(value) => target.#field_ident = Some(value.clone()),
+                    #crate_name::MaybeUndefined::Null => target.#field_ident = None,
+                    #crate_name::MaybeUndefined::Undefined => {}
+                }
+            }
+        } else {
+            quote! {
+                if let #crate_name::MaybeUndefined::Value(value) = &self.#field_ident {
+                    target.#field_ident = value.clone();
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        impl #patch_ident {
+            /// Set the fields given in the patch, clearing optional fields set to `null`
+            pub fn apply(&self, target: &mut #ident) {
+                #(#apply_fields)*
+            }
+        }
+    })
+}
+
+impl ToTokens for PatchInput {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let define_patch_struct = define_patch_struct(self).into_token_stream();
+        let impl_input_object = impl_input_object(self);
+        let impl_register = impl_register(self).into_token_stream();
+        let impl_from_value = impl_from_value(self).into_token_stream();
+        let impl_apply = impl_apply(self).into_token_stream();
+        tokens.extend(quote! {
+            #define_patch_struct
+            #impl_input_object
+            #impl_register
+            #impl_from_value
+            #impl_apply
+        });
+    }
+}
@@ -23,6 +23,7 @@ use crate::utils::impl_block::BaseFnArg;
 use crate::utils::impl_block::BaseItemImpl;
 use crate::utils::impl_block::BaseMethod;
 use crate::utils::macros::*;
+use crate::utils::map_as::MapAs;
 use crate::utils::rename_rule::RenameRule;
 use crate::utils::type_utils::get_type_path;
 use crate::utils::with_attributes::WithAttributes;
@@ -39,6 +40,18 @@ pub struct ResolvedObjectFieldsArgAttrs {
 
     #[darling(default)]
     pub ctx: bool,
+
+    #[darling(default)]
+    pub data: bool,
+
+    #[darling(default)]
+    pub lookahead: bool,
+
+    #[darling(default)]
+    pub selection: bool,
+
+    #[darling(default)]
+    pub flatten: bool,
 }
 
 impl Attributes for ResolvedObjectFieldsArgAttrs {
@@ -48,6 +61,8 @@ impl Attributes for ResolvedObjectFieldsArgAttrs {
 #[derive(Default, Debug, Clone)]
 pub struct ResolvedObjectFieldsArgContext {
     pub rename_args: Option<RenameRule>,
+    /// the method is defined in a `ComplexObjectFields` impl block
+    pub complex: bool,
 }
 
 from_fn_arg!(ResolvedObjectFieldsArg,
@@ -71,6 +86,15 @@ pub struct ResolvedObjectFieldsMethodAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub map_as: Option<MapAs>,
+
+    #[darling(default)]
+    pub errors_as_data: bool,
+
+    #[darling(default)]
+    pub result_union_name: Option<String>,
 }
 
 impl Attributes for ResolvedObjectFieldsMethodAttrs {
@@ -81,6 +105,8 @@ impl Attributes for ResolvedObjectFieldsMethodAttrs {
 pub struct ResolvedObjectFieldsMethodContext {
     pub rename_args: Option<RenameRule>,
     pub rename_fields: Option<RenameRule>,
+    /// the methods are defined in a `ComplexObjectFields` impl block
+    pub complex: bool,
 }
 
 from_impl_item_method!(
@@ -96,6 +122,7 @@ impl MakeContext<ResolvedObjectFieldsArgContext> for ResolvedObjectFieldsMethod
     fn make_context(&self) -> ResolvedObjectFieldsArgContext {
         ResolvedObjectFieldsArgContext {
             rename_args: self.attrs.rename_args.or(self.ctx.rename_args),
+            complex: self.ctx.complex,
         }
     }
 }
@@ -128,6 +155,7 @@ impl MakeContext<ResolvedObjectFieldsMethodContext> for ResolvedObjectFields {
         ResolvedObjectFieldsMethodContext {
             rename_args: self.attrs.rename_args,
             rename_fields: self.attrs.rename_fields,
+            complex: false,
         }
     }
 }
@@ -186,11 +214,14 @@ impl CommonField for ResolvedObjectFieldsMethod {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
-    fn get_args_rename_rule(&self) -> Option<&RenameRule> {
-        self.attrs
-            .rename_args
-            .as_ref()
-            .or(self.ctx.rename_args.as_ref())
+    fn get_map_as(&self) -> Option<&MapAs> {
+        self.attrs.map_as.as_ref()
+    }
+    fn get_errors_as_data(&self) -> bool {
+        self.attrs.errors_as_data
+    }
+    fn get_result_union_name(&self) -> Option<&str> {
+        self.attrs.result_union_name.as_deref()
     }
 }
 
@@ -214,6 +245,22 @@ impl CommonArg for ResolvedObjectFieldsArg {
     fn is_marked_as_ctx(&self) -> bool {
         self.attrs.ctx
     }
+
+    fn is_marked_as_data(&self) -> bool {
+        self.attrs.data
+    }
+
+    fn is_marked_as_lookahead(&self) -> bool {
+        self.attrs.lookahead
+    }
+
+    fn is_marked_as_selection(&self) -> bool {
+        self.attrs.selection
+    }
+
+    fn get_flatten(&self) -> bool {
+        self.attrs.flatten
+    }
 }
 
 impl CommonMethod for ResolvedObjectFieldsMethod {
@@ -239,6 +286,13 @@ impl ArgImplementor for ResolvedObjectFieldsArg {
         let crate_name = get_crate_name();
         let arg_ident = common::get_arg_ident(self);
 
+        if self.ctx.complex {
+            return Ok(quote! {
+                let parent = ctx.parent_value.try_downcast_ref::<<__P as #crate_name::internal::FlattenPath<Self>>::Parent>()?;
+                let #arg_ident = <__P as #crate_name::internal::FlattenPath<Self>>::project(parent);
+            });
+        }
+
         Ok(quote! {
             let parent = ctx.parent_value.try_downcast_ref::<<Self as #crate_name::internal::ParentType>::Type>()?.into();
             let #arg_ident = parent;
@@ -271,7 +325,12 @@ impl FieldImplementor for ResolvedObjectFieldsMethod {
     }
 
     fn get_field_argument_definition(&self) -> darling::Result<TokenStream> {
-        common::get_argument_definitions(self.get_args()?)
+        let check_flatten_args = common::get_check_flatten_args_code(self)?;
+        let argument_definitions = common::get_argument_definitions(self.get_args()?)?;
+        Ok(quote! {
+            #check_flatten_args
+            #argument_definitions
+        })
     }
 
     fn get_field_description_code(&self) -> darling::Result<TokenStream> {
@@ -37,6 +37,9 @@ pub struct ScalarAttrs {
     #[darling(default)]
     specified_by_url: Option<String>,
 
+    #[darling(default)]
+    pub serde: bool,
+
     #[darling(default, multiple)]
     #[darling(rename = "register")]
     pub registers: Vec<RegisterAttr>,
@@ -95,34 +98,45 @@ fn impl_scalar(scalar: &Scalar) -> darling::Result<TokenStream> {
     })
 }
 
+fn get_to_value_code(scalar: &Scalar, value: TokenStream) -> TokenStream {
+    let crate_name = get_crate_name();
+    if scalar.attrs.serde {
+        quote!(#crate_name::internal::to_scalar_value(#value)?)
+    } else {
+        quote!(#crate_name::ScalarValue::to_value(#value))
+    }
+}
+
 fn impl_resolved_own(scalar: &Scalar) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let object_ident = scalar.get_ident();
     let (_, ty_generics, where_clause) = scalar.get_generics()?.split_for_impl();
     let (generics_with_lifetime, lifetime) = add_new_lifetime_to_generics(scalar.get_generics()?);
     let (impl_generics, _, _) = generics_with_lifetime.split_for_impl();
+    let to_value = get_to_value_code(scalar, quote!(&self));
 
     Ok(quote! {
         impl #impl_generics #crate_name::internal::ResolveOwned<#lifetime> for #object_ident #ty_generics #where_clause {
             fn resolve_owned(self, _ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<#lifetime>>> {
-                let value = #crate_name::ScalarValue::to_value(&self);
+                let value = #to_value;
                 Ok(Some(#crate_name::FieldValue::value(value)))
             }
         }
     })
 }
 
-pub fn impl_resolve_ref(scalar: &impl CommonObject) -> darling::Result<TokenStream> {
+pub fn impl_resolve_ref(scalar: &Scalar) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
     let object_ident = scalar.get_ident();
     let (_, ty_generics, where_clause) = scalar.get_generics()?.split_for_impl();
     let (generics_with_lifetime, lifetime) = add_new_lifetime_to_generics(scalar.get_generics()?);
     let (impl_generics, _, _) = generics_with_lifetime.split_for_impl();
+    let to_value = get_to_value_code(scalar, quote!(self));
 
     Ok(quote! {
         impl #impl_generics #crate_name::internal::ResolveRef<#lifetime> for #object_ident #ty_generics #where_clause {
             fn resolve_ref(&#lifetime self, _ctx: &#crate_name::Context) -> #crate_name::Result<Option<#crate_name::FieldValue<#lifetime>>> {
-                let value = #crate_name::ScalarValue::to_value(self);
+                let value = #to_value;
                 Ok(Some(#crate_name::FieldValue::value(value)))
             }
         }
@@ -142,6 +156,29 @@ fn impl_from_value(scalar: &impl CommonObject) -> darling::Result<TokenStream> {
     ))
 }
 
+fn impl_serde_scalar_value(scalar: &Scalar) -> darling::Result<TokenStream> {
+    if !scalar.attrs.serde {
+        return Ok(quote!());
+    }
+    let crate_name = get_crate_name();
+    let ident = scalar.get_ident();
+    let (impl_generics, ty_generics, where_clause) = scalar.get_generics()?.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::ScalarValue for #ident #ty_generics #where_clause {
+            fn from_value(value: #crate_name::Value) -> #crate_name::Result<Self> {
+                #crate_name::internal::from_scalar_value(value)
+            }
+
+            /// Values serde can't represent, e.g. maps with non-string keys, become `null`.
+            /// Resolvers report them as errors instead.
+            fn to_value(&self) -> #crate_name::Value {
+                #crate_name::internal::to_scalar_value(self).unwrap_or(#crate_name::Value::Null)
+            }
+        }
+    })
+}
+
 pub fn get_specified_by_url_code(scalar: &Scalar) -> darling::Result<TokenStream> {
     let specified_by_url = scalar.attrs.specified_by_url.as_deref();
     Ok(match specified_by_url {
@@ -200,6 +237,7 @@ impl ToTokens for Scalar {
         let impl_resolve_ref = impl_resolve_ref(self).into_token_stream();
         let impl_from_value = impl_from_value(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
+        let impl_serde_scalar_value = impl_serde_scalar_value(self).into_token_stream();
         let impl_suppress = impl_suppress_clippy_error(self);
         tokens.extend(quote! {
             #impl_scalar
@@ -207,6 +245,7 @@ impl ToTokens for Scalar {
             #impl_resolve_ref
             #impl_from_value
             #impl_register
+            #impl_serde_scalar_value
             #impl_suppress
         })
     }
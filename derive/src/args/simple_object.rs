@@ -9,6 +9,7 @@ use syn::Path;
 use crate::args::common;
 use crate::args::common::FieldImplementor;
 use crate::args::common::get_register_interface_code;
+use crate::args::common::replace_type_generics_with_static;
 use crate::utils::common::CommonField;
 use crate::utils::common::CommonInterfaceAttrs;
 use crate::utils::common::CommonObject;
@@ -23,8 +24,10 @@ use crate::utils::error::IntoTokenStream;
 use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::interface_attr::InterfaceMarkAttr;
 use crate::utils::macros::*;
+use crate::utils::map_as::MapAs;
 use crate::utils::register_attr::RegisterAttr;
 use crate::utils::rename_rule::RenameRule;
+use crate::utils::type_utils::get_owned_type;
 use crate::utils::with_attributes::WithAttributes;
 use crate::utils::with_context::MakeContext;
 use crate::utils::with_context::WithContext;
@@ -41,6 +44,24 @@ pub struct SimpleObjectFieldAttrs {
 
     #[darling(default)]
     pub deprecation: Deprecation,
+
+    #[darling(default)]
+    pub map_as: Option<MapAs>,
+
+    #[darling(default)]
+    pub flatten: bool,
+
+    #[darling(default)]
+    pub resolve_with: Option<Path>,
+
+    #[darling(default)]
+    pub ty: Option<syn::Type>,
+
+    #[darling(default)]
+    pub input_skip: bool,
+
+    #[darling(default)]
+    pub output_skip: bool,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -86,6 +107,12 @@ pub struct SimpleObjectAttrs {
     #[darling(default, multiple)]
     #[darling(rename = "implements")]
     pub impls: Vec<InterfaceImplAttr>,
+
+    #[darling(default)]
+    pub complex: bool,
+
+    #[darling(default)]
+    pub input_name: Option<String>,
 }
 
 from_derive_input!(
@@ -148,11 +175,26 @@ impl CommonField for SimpleObjectField {
     }
 
     fn get_type(&self) -> darling::Result<&syn::Type> {
-        Ok(&self.ty)
+        match (&self.attrs.resolve_with, &self.attrs.ty) {
+            (None, None) => Ok(&self.ty),
+            (Some(_), Some(_)) if self.attrs.flatten => Err(darling::Error::custom(
+                "`resolve_with` can't be used with `flatten`",
+            )
+            .with_span(&self.ident)),
+            (Some(_), Some(ty)) => Ok(ty),
+            (Some(resolve_with), None) => Err(darling::Error::custom(
+                "`resolve_with` requires `ty` to be set to the output type",
+            )
+            .with_span(resolve_with)),
+            (None, Some(ty)) => Err(darling::Error::custom(
+                "`ty` can only be used with `resolve_with`",
+            )
+            .with_span(ty)),
+        }
     }
 
     fn get_skip(&self) -> bool {
-        self.attrs.skip
+        self.attrs.skip || self.attrs.output_skip
     }
 
     fn get_doc(&self) -> darling::Result<Option<String>> {
@@ -164,6 +206,12 @@ impl CommonField for SimpleObjectField {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         self.ctx.rename_fields.as_ref()
     }
+    fn get_map_as(&self) -> Option<&MapAs> {
+        self.attrs.map_as.as_ref()
+    }
+    fn get_flatten(&self) -> bool {
+        self.attrs.flatten
+    }
 }
 
 impl FieldImplementor for SimpleObjectField {
@@ -172,11 +220,18 @@ impl FieldImplementor for SimpleObjectField {
     }
 
     fn get_execute_code(&self) -> darling::Result<TokenStream> {
+        let crate_name = get_crate_name();
         let resolver_ident = get_resolver_ident(self)?;
+        let resolve = if self.attrs.resolve_with.is_some() {
+            quote!(let value = Self::#resolver_ident(parent, &ctx)?;)
+        } else {
+            quote!(let value = Self::#resolver_ident(parent);)
+        };
 
         Ok(quote! {
-            let parent = ctx.parent_value.try_downcast_ref::<Self>()?;
-            let value = Self::#resolver_ident(parent);
+            let parent = ctx.parent_value.try_downcast_ref::<<__P as #crate_name::internal::FlattenPath<Self>>::Parent>()?;
+            let parent = <__P as #crate_name::internal::FlattenPath<Self>>::project(parent);
+            #resolve
         })
     }
 
@@ -223,10 +278,20 @@ fn get_resolver_ident(field: &impl CommonField) -> darling::Result<Ident> {
     Ok(resolver_ident)
 }
 
-fn impl_resolver(field: &impl CommonField) -> darling::Result<TokenStream> {
+fn impl_resolver(field: &SimpleObjectField) -> darling::Result<TokenStream> {
     let field_ident = field.get_ident()?;
     let resolver_ident = get_resolver_ident(field)?;
-    let ty = field.get_type()?;
+    if let Some(resolve_with) = &field.attrs.resolve_with {
+        let crate_name = get_crate_name();
+        let ty = field.get_type()?;
+        return Ok(quote! {
+            #[allow(clippy::needless_question_mark)]
+            fn #resolver_ident(&self, ctx: &#crate_name::Context) -> #crate_name::Result<#ty> {
+                Ok(#resolve_with(&self.#field_ident, ctx)?)
+            }
+        });
+    }
+    let ty = &field.ty;
     Ok(quote! {
         fn #resolver_ident(&self) -> &#ty {
             &self.#field_ident
@@ -234,11 +299,7 @@ fn impl_resolver(field: &impl CommonField) -> darling::Result<TokenStream> {
     })
 }
 
-fn impl_resolvers<O, F>(object: &O) -> darling::Result<TokenStream>
-where
-    O: CommonObject + GetFields<F>,
-    F: CommonField,
-{
+fn impl_resolvers(object: &SimpleObject) -> darling::Result<TokenStream> {
     let ident = object.get_ident();
     let fields = object
         .get_fields()?
@@ -275,10 +336,276 @@ fn root_register_code(object: &SimpleObject) -> TokenStream {
     quote!(#root #mutation_root)
 }
 
+fn get_flatten_fields(object: &SimpleObject) -> darling::Result<Vec<&SimpleObjectField>> {
+    Ok(object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip() && field.get_flatten())
+        .collect())
+}
+
+fn get_define_fields_code(object: &SimpleObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+        .map(|field| {
+            if field.get_flatten() {
+                let ty = common::get_field_type(field)?;
+                Ok(quote! {
+                    let object = <#ty as #crate_name::internal::FlattenObject>::flatten_fields::<#crate_name::internal::FlattenThen<__P, Self>>(object);
+                })
+            } else {
+                common::build_field(field)
+            }
+        })
+        .collect()
+}
+
+fn get_register_flatten_types_code(object: &SimpleObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    get_flatten_fields(object)?
+        .into_iter()
+        .map(|field| {
+            let ty = common::get_field_type(field)?;
+            Ok(quote! {
+                let registry = <#ty as #crate_name::internal::FlattenObject>::register_flatten_types(registry);
+            })
+        })
+        .collect()
+}
+
+fn get_register_complex_types_code(object: &SimpleObject) -> TokenStream {
+    if !object.attrs.complex {
+        return quote!();
+    }
+    let crate_name = get_crate_name();
+    quote! {
+        let registry = <Self as #crate_name::internal::ComplexObject>::register_complex_types(registry);
+    }
+}
+
+fn get_define_complex_fields_code(object: &SimpleObject) -> TokenStream {
+    if !object.attrs.complex {
+        return quote!();
+    }
+    let crate_name = get_crate_name();
+    quote! {
+        let object = <Self as #crate_name::internal::ComplexObject>::define_complex_fields::<__P>(object);
+    }
+}
+
+fn get_check_flatten_fields_code(object: &SimpleObject) -> darling::Result<TokenStream> {
+    if get_flatten_fields(object)?.is_empty() && !object.attrs.complex {
+        return Ok(quote!());
+    }
+    let crate_name = get_crate_name();
+    Ok(quote! {
+        #crate_name::internal::check_flatten_field_names(
+            &<Self as #crate_name::internal::Object>::get_object_type_name(),
+            <Self as #crate_name::internal::FlattenObject>::get_flatten_field_names(),
+        );
+    })
+}
+
+fn impl_flatten_paths(object: &SimpleObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+    get_flatten_fields(object)?
+        .into_iter()
+        .map(|field| {
+            let ty = common::get_field_type(field)?;
+            let field_ident = field.get_ident()?;
+            Ok(quote! {
+                impl #impl_generics #crate_name::internal::FlattenPath<#ty> for #ident #ty_generics #where_clause {
+                    type Parent = Self;
+                    fn project(parent: &Self) -> &#ty {
+                        &parent.#field_ident
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+fn impl_flatten_object(object: &SimpleObject) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    let field_names = object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+        .map(|field| {
+            if field.get_flatten() {
+                let ty = common::get_field_type(field)?;
+                Ok(quote! {
+                    names.extend(<#ty as #crate_name::internal::FlattenObject>::get_flatten_field_names());
+                })
+            } else {
+                let name = common::get_field_name(field)?;
+                Ok(quote! {
+                    names.push(#name);
+                })
+            }
+        })
+        .collect::<darling::Result<TokenStream>>()?;
+    let register_nested_types = common::get_nested_type_register_code(object)?;
+    let register_flatten_types = get_register_flatten_types_code(object)?;
+    let define_fields = get_define_fields_code(object)?;
+    let complex_field_names = object.attrs.complex.then(|| {
+        quote! {
+            names.extend(<Self as #crate_name::internal::ComplexObject>::get_complex_field_names());
+        }
+    });
+    let register_complex_types = get_register_complex_types_code(object);
+    let define_complex_fields = get_define_complex_fields_code(object);
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::FlattenObject for #ident #ty_generics #where_clause {
+            fn get_flatten_field_names() -> Vec<&'static str> {
+                let mut names = Vec::new();
+                #field_names
+                #complex_field_names
+                names
+            }
+            fn register_flatten_types(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #register_nested_types
+                #register_flatten_types
+                #register_complex_types
+                registry
+            }
+            fn flatten_fields<__P: #crate_name::internal::FlattenPath<Self>>(object: #crate_name::dynamic::Object) -> #crate_name::dynamic::Object {
+                #define_fields
+                #define_complex_fields
+                object
+            }
+        }
+    })
+}
+
+fn get_input_fields(object: &SimpleObject) -> darling::Result<Vec<&SimpleObjectField>> {
+    let mut errors = darling::Error::accumulator();
+    let fields = object
+        .data
+        .fields
+        .iter()
+        .filter(|field| !field.attrs.skip && !field.attrs.input_skip)
+        .filter(|field| {
+            if field.attrs.flatten {
+                errors.push(
+                    darling::Error::custom(
+                        "`flatten` fields must be marked with `input_skip` when `input_name` is set",
+                    )
+                    .with_span(&field.ident),
+                );
+                return false;
+            }
+            true
+        })
+        .collect();
+    errors.finish_with(fields)
+}
+
+fn get_register_input_object_code(object: &SimpleObject) -> darling::Result<TokenStream> {
+    if object.attrs.input_name.is_none() {
+        return Ok(quote!());
+    }
+    let crate_name = get_crate_name();
+    let fields = get_input_fields(object)?;
+    let register_types = fields.iter().map(|field| {
+        let ty = replace_type_generics_with_static(get_owned_type(&field.ty));
+        quote! {
+            let registry = registry.register::<#ty>();
+        }
+    });
+    let define_fields = fields
+        .iter()
+        .map(|field| {
+            let ty = get_owned_type(&field.ty);
+            let field_name = common::get_input_field_name(*field)?;
+            let description = common::field_description(*field)?;
+            Ok(quote! {
+                let field = #crate_name::dynamic::InputValue::new(#field_name, <#ty as #crate_name::internal::GetInputTypeRef>::get_input_type_ref());
+                #description
+                let object = object.field(field);
+            })
+        })
+        .collect::<darling::Result<Vec<_>>>()?;
+    let define_object = common::impl_define_input_object();
+    let description = common::object_description(object.get_doc()?.as_deref())?;
+    let register_object_code = common::register_object_code();
+
+    Ok(quote! {
+        let registry = {
+            #(#register_types)*
+            #define_object
+            #description
+            #(#define_fields)*
+            #register_object_code
+        };
+    })
+}
+
+fn impl_input_object(object: &SimpleObject) -> darling::Result<TokenStream> {
+    let Some(input_name) = &object.attrs.input_name else {
+        return Ok(quote!());
+    };
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+    let input_fields = get_input_fields(object)?;
+
+    let fields_value = object
+        .data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let item = syn::Ident::new(&format!("field{}", index), field.ident.span());
+            if !input_fields.iter().any(|input| input.ident == field.ident) {
+                return Ok(quote! {
+                    let #item = Default::default();
+                });
+            }
+            let field_name = common::get_input_field_name(field)?;
+            Ok(quote! {
+                let #item = #crate_name::internal::FromValue::from_value(__object.try_get(#field_name)).map_err(|e| e.into_field_error(#field_name))?;
+            })
+        })
+        .collect::<darling::Result<Vec<_>>>()?;
+    let fields_usage = object.data.fields.iter().enumerate().map(|(index, field)| {
+        let field_ident = &field.ident;
+        let item = syn::Ident::new(&format!("field{}", index), field_ident.span());
+        quote!(#field_ident: #item,)
+    });
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::InputTypeName for #ident #ty_generics #where_clause {
+            fn get_input_type_name() -> std::borrow::Cow<'static, str> {
+                #input_name.into()
+            }
+        }
+        impl #impl_generics #crate_name::internal::InputObject for #ident #ty_generics #where_clause {}
+        impl #impl_generics #crate_name::internal::FromValue for #ident #ty_generics #where_clause {
+            fn from_value(__value: #crate_name::Result<#crate_name::dynamic::ValueAccessor>) -> #crate_name::internal::InputValueResult<Self> {
+                let __value = __value?;
+                let __object = __value.object()?;
+                #(#fields_value)*
+                Ok(Self {
+                    #(#fields_usage)*
+                })
+            }
+        }
+    })
+}
+
 fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
     let crate_name = get_crate_name();
 
-    let register_nested_types = common::get_nested_type_register_code(object).into_token_stream();
     let root_register = root_register_code(object);
 
     let ident = &object.ident;
@@ -288,7 +615,8 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
     let implement = common::get_add_implement_code(object, object.get_impls())?;
 
     let description = common::object_description(object.get_doc()?.as_deref())?;
-    let define_fields = common::get_define_fields_code(object)?;
+    let check_flatten_fields = get_check_flatten_fields_code(object)?;
+    let register_input_object = get_register_input_object_code(object)?;
     let register_object_code = common::register_object_code();
 
     let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
@@ -302,10 +630,12 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
 
                 #register_interface_code
 
-                #register_nested_types
+                let registry = <Self as #crate_name::internal::FlattenObject>::register_flatten_types(registry);
 
                 #root_register
 
+                #register_input_object
+
                 #define_object
 
                 #implement
@@ -314,7 +644,9 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
 
                 #description
 
-                #define_fields
+                #check_flatten_fields
+
+                let object = <Self as #crate_name::internal::FlattenObject>::flatten_fields::<#crate_name::internal::FlattenRoot<Self>>(object);
 
                 #register_object_code
             }
@@ -322,6 +654,50 @@ fn impl_register(object: &SimpleObject) -> darling::Result<TokenStream> {
     })
 }
 
+fn impl_selectable_fields(object: &SimpleObject) -> darling::Result<TokenStream> {
+    if object.attrs.mutation_root {
+        // the mutation root has no fields to select
+        return Ok(quote!());
+    }
+    let crate_name = get_crate_name();
+    let ident = &object.ident;
+    let (impl_generics, ty_generics, where_clause) = object.generics.split_for_impl();
+
+    let fields = object
+        .get_fields()?
+        .iter()
+        .filter(|field| !field.get_skip())
+        .map(|field| {
+            let field_ident = field.get_ident()?.to_string();
+            if field.get_flatten() {
+                let ty = common::get_field_type(field)?;
+                Ok(quote! {
+                    fields.extend(
+                        <#ty as #crate_name::internal::FlattenObject>::get_flatten_field_names()
+                            .into_iter()
+                            .map(|name| (name, #field_ident)),
+                    );
+                })
+            } else {
+                let name = common::get_field_name(field)?;
+                Ok(quote! {
+                    fields.push((#name, #field_ident));
+                })
+            }
+        })
+        .collect::<darling::Result<TokenStream>>()?;
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::SelectableFields for #ident #ty_generics #where_clause {
+            fn get_selectable_fields() -> Vec<(&'static str, &'static str)> {
+                let mut fields = Vec::new();
+                #fields
+                fields
+            }
+        }
+    })
+}
+
 impl ToTokens for SimpleObject {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let impl_object = common::impl_object(self).into_token_stream();
@@ -330,6 +706,10 @@ impl ToTokens for SimpleObject {
         let impl_resolvers = impl_resolvers(self).into_token_stream();
         let impl_register = impl_register(self).into_token_stream();
         let impl_interface_mark = common::impl_interface_mark(self).into_token_stream();
+        let impl_flatten_paths = impl_flatten_paths(self).into_token_stream();
+        let impl_flatten_object = impl_flatten_object(self).into_token_stream();
+        let impl_input_object = impl_input_object(self).into_token_stream();
+        let impl_selectable_fields = impl_selectable_fields(self).into_token_stream();
 
         tokens.extend(quote! {
             #impl_object
@@ -338,6 +718,10 @@ impl ToTokens for SimpleObject {
             #impl_resolve_ref
             #impl_resolvers
             #impl_register
+            #impl_flatten_paths
+            #impl_flatten_object
+            #impl_input_object
+            #impl_selectable_fields
         })
     }
 }
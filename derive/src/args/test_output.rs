@@ -65,6 +65,79 @@ fn test_app() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn test_args() {
+    let input = r#"
+        struct PageArgs {
+            pub first: Option<i32>,
+        }
+    "#;
+
+    let pretty = pretty_derive::<Args>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_filter_input() {
+    let input = r#"
+        pub struct User {
+            pub name: String,
+            pub age: Option<i32>,
+        }
+    "#;
+
+    let pretty = pretty_derive::<FilterInput>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_order_by_input() {
+    let input = r#"
+        pub struct User {
+            pub name: String,
+            pub age: Option<i32>,
+        }
+    "#;
+
+    let pretty = pretty_derive::<OrderByInput>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_patch_input() {
+    let input = r#"
+        pub struct User {
+            pub name: String,
+            pub nickname: Option<String>,
+        }
+    "#;
+
+    let pretty = pretty_derive::<PatchInput>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_graphql_error() {
+    let input = r#"
+        enum MyError {
+            #[graphql(code = "NOT_FOUND", message = "User {id} not found")]
+            NotFound { id: String },
+            #[graphql(message = "Invalid value {0}")]
+            InvalidValue(#[graphql(name = "value")] String),
+            #[graphql(message = "Access denied")]
+            Forbidden,
+        }
+    "#;
+
+    let pretty = pretty_derive::<GraphQLError>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn test_expand_object() {
     let input1 = r#"
@@ -87,6 +160,26 @@ fn test_expand_object() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn test_expand_interface() {
+    let input1 = r#"
+        struct NodeAudit<'a>(&'a dyn Node);
+    "#;
+
+    let input2 = r#"
+    impl NodeAudit<'_> {
+        fn audit_log(&self) -> Vec<String> {
+            vec![self.0.id()]
+        }
+    }
+    "#;
+
+    let pretty1 = pretty_derive::<ExpandInterface>(input1);
+    let pretty2 = pretty_expand_item_impl::<ExpandInterfaceFields>(input2);
+    let output = md(&[&pretty1, &pretty2]);
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn test_enum() {
     let input = r#"
@@ -165,6 +258,25 @@ fn test_mutation() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn test_relay_mutation() {
+    let input = r#"
+        impl MyMutation {
+            #[graphql(relay_mutation)]
+            fn create_user(name: String) -> User {
+                User { name }
+            }
+        }
+    "#;
+
+    let mut item: syn::ItemImpl = syn::parse_str(input).unwrap();
+    let expanded = MutationFields::from_item_impl(&mut item).unwrap();
+    let pretty_item = pretty_expand(&item);
+    let pretty_expanded = pretty_expand(&expanded);
+    let output = md(&[&input.unindent(), &pretty_item, &pretty_expanded]);
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn test_resoled_object() {
     let input1 = r#"
@@ -211,6 +323,29 @@ fn test_simple_object() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn test_complex_object() {
+    let input1 = r#"
+        #[graphql(complex)]
+        struct Example {
+            pub field: String,
+        }
+    "#;
+
+    let input2 = r#"
+        impl Example {
+            fn computed(&self) -> String {
+                self.field.to_uppercase()
+            }
+        }
+    "#;
+
+    let pretty1 = pretty_derive::<SimpleObject>(input1);
+    let pretty2 = pretty_expand_item_impl::<ComplexObjectFields>(input2);
+    let output = md(&[&pretty1, &pretty2]);
+    insta::assert_snapshot!(output);
+}
+
 #[test]
 fn test_union() {
     let input = r#"
@@ -224,3 +359,32 @@ fn test_union() {
     let output = md(&[&pretty]);
     insta::assert_snapshot!(output);
 }
+
+#[test]
+fn test_expand_union() {
+    let input = r#"
+        #[graphql(target(Animal))]
+        enum FarmAnimal {
+            Cow(Cow),
+            Pig(Pig),
+        }
+    "#;
+
+    let pretty = pretty_derive::<ExpandUnion>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_expand_input_object() {
+    let input = r#"
+        #[graphql(target(CreateOrderInput))]
+        struct LoyaltyOrderInput {
+            loyalty_code: Option<String>,
+        }
+    "#;
+
+    let pretty = pretty_derive::<ExpandInputObject>(input);
+    let output = md(&[&pretty]);
+    insta::assert_snapshot!(output);
+}
@@ -264,18 +264,64 @@ fn impl_register(union: &Union) -> darling::Result<TokenStream> {
     })
 }
 
+fn impl_union_members(union: &Union) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = union.get_ident();
+    let register_nested_types = common::get_nested_type_register_code(union).into_token_stream();
+    let member_names = union.data.iter().map(|item| {
+        let ty = get_owned_type(&item.fields.ty);
+        quote!(<#ty as #crate_name::internal::Object>::get_object_type_name())
+    });
+    let (impl_generics, ty_generics, where_clause) = union.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::internal::UnionMembers for #ident #ty_generics #where_clause {
+            fn register_members(registry: #crate_name::internal::Registry) -> #crate_name::internal::Registry {
+                #register_nested_types
+                registry
+            }
+
+            fn get_member_type_names() -> Vec<std::borrow::Cow<'static, str>> {
+                vec![#(#member_names),*]
+            }
+        }
+    })
+}
+
+fn impl_union_marks(union: &Union) -> darling::Result<TokenStream> {
+    let crate_name = get_crate_name();
+    let ident = union.get_ident();
+    // a member type may be a generic parameter, so the marks are only made for plain unions
+    if !union.generics.params.is_empty() {
+        return Ok(quote!());
+    }
+    let marks = union.data.iter().map(|item| {
+        let ty = get_owned_type(&item.fields.ty);
+        quote! {
+            impl #crate_name::internal::UnionMark<#ident> for #ty {}
+        }
+    });
+    Ok(quote! {
+        #(#marks)*
+    })
+}
+
 impl ToTokens for Union {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let impl_union = impl_union(self).into_token_stream();
         let resolve_owned = define_resolve_owned_for_union(self).into_token_stream();
         let resolve_ref = define_resolve_ref_for_union(self).into_token_stream();
         let register = impl_register(self).into_token_stream();
+        let union_members = impl_union_members(self).into_token_stream();
+        let union_marks = impl_union_marks(self).into_token_stream();
 
         tokens.extend(quote! {
             #impl_union
             #resolve_owned
             #resolve_ref
             #register
+            #union_members
+            #union_marks
         });
     }
 }
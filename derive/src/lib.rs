@@ -38,6 +38,30 @@ pub fn drive_expand_object(input: proc_macro::TokenStream) -> proc_macro::TokenS
     }
 }
 
+#[proc_macro_derive(ExpandInputObject, attributes(graphql))]
+pub fn drive_expand_input_object(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::ExpandInputObject::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(ExpandUnion, attributes(graphql))]
+pub fn drive_expand_union(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::ExpandUnion::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(ExpandInterface, attributes(graphql))]
+pub fn drive_expand_interface(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::ExpandInterface::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn ResolvedObjectFields(
@@ -57,6 +81,25 @@ pub fn ResolvedObjectFields(
     .into()
 }
 
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn ComplexObjectFields(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut item = parse_macro_input!(item as syn::ItemImpl);
+    let data = args::ComplexObjectFields::from_item_impl(&mut item);
+    let extension = match data {
+        Ok(obj) => obj.into_token_stream(),
+        Err(err) => err.write_errors(),
+    };
+    (quote! {
+        #item
+        #extension
+    })
+    .into()
+}
+
 #[proc_macro_derive(InputObject, attributes(graphql))]
 pub fn drive_input_object(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     match args::InputObject::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
@@ -65,6 +108,22 @@ pub fn drive_input_object(input: proc_macro::TokenStream) -> proc_macro::TokenSt
     }
 }
 
+#[proc_macro_derive(Args, attributes(graphql))]
+pub fn drive_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::Args::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(FilterInput, attributes(graphql))]
+pub fn drive_filter_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::FilterInput::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[proc_macro_derive(OneOfInput, attributes(graphql))]
 pub fn drive_one_of_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     match args::OneOfInput::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
@@ -73,6 +132,30 @@ pub fn drive_one_of_input(input: proc_macro::TokenStream) -> proc_macro::TokenSt
     }
 }
 
+#[proc_macro_derive(OrderByInput, attributes(graphql))]
+pub fn drive_order_by_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::OrderByInput::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(PatchInput, attributes(graphql))]
+pub fn drive_patch_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::PatchInput::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro_derive(GraphQLError, attributes(graphql))]
+pub fn drive_graphql_error(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match args::GraphQLError::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+        Ok(object_args) => object_args.into_token_stream().into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[proc_macro_derive(Enum, attributes(graphql))]
 pub fn drive_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     match args::Enum::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
@@ -97,6 +180,25 @@ pub fn drive_app(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
 }
 
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn ExpandInterfaceFields(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut item = parse_macro_input!(item as syn::ItemImpl);
+    let data = args::ExpandInterfaceFields::from_item_impl(&mut item);
+    let extension = match data {
+        Ok(obj) => obj.into_token_stream(),
+        Err(err) => err.write_errors(),
+    };
+    (quote! {
+        #item
+        #extension
+    })
+    .into()
+}
+
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn ExpandObjectFields(
@@ -2,6 +2,7 @@ use crate::utils::deprecation::Deprecation;
 use crate::utils::impl_block::BaseFnArg;
 use crate::utils::interface_attr::InterfaceImplAttr;
 use crate::utils::interface_attr::InterfaceMarkAttr;
+use crate::utils::map_as::MapAs;
 use crate::utils::rename_rule::RenameRule;
 
 pub trait CommonObject {
@@ -27,9 +28,21 @@ pub trait CommonField {
     fn get_field_rename_rule(&self) -> Option<&RenameRule> {
         None
     }
-    fn get_args_rename_rule(&self) -> Option<&RenameRule> {
+    fn get_map_as(&self) -> Option<&MapAs> {
         None
     }
+    /// resolve a `Result<T, E>` as a union of `T` and the members of `E`
+    fn get_errors_as_data(&self) -> bool {
+        false
+    }
+    /// the name of the `errors_as_data` union, instead of `<Field>Result`
+    fn get_result_union_name(&self) -> Option<&str> {
+        None
+    }
+    /// hoist the fields of the field type into the parent type
+    fn get_flatten(&self) -> bool {
+        false
+    }
 }
 
 pub trait CommonMethod: CommonField {
@@ -50,6 +63,22 @@ pub trait CommonArg {
         None
     }
     fn is_marked_as_ctx(&self) -> bool;
+    /// resolve the argument from the request or schema data
+    fn is_marked_as_data(&self) -> bool {
+        false
+    }
+    /// pass the `Lookahead` of the field
+    fn is_marked_as_lookahead(&self) -> bool {
+        false
+    }
+    /// pass the `SelectedFields` of the field return type
+    fn is_marked_as_selection(&self) -> bool {
+        false
+    }
+    /// spread the fields of the argument type as separate arguments
+    fn get_flatten(&self) -> bool {
+        false
+    }
 }
 
 pub trait GetFields<F> {
@@ -3,22 +3,40 @@ use darling::FromField;
 use darling::FromGenerics;
 use darling::FromVariant;
 use darling::ast::Data;
+use darling::util::Ignored;
+
+use crate::utils::with_context::SetContext;
 
 #[derive(FromDeriveInput)]
 pub struct Base<V: FromVariant, F: FromField, G: FromGenerics> {
     pub ident: syn::Ident,
+    pub vis: syn::Visibility,
     pub generics: G,
     pub data: Data<V, F>,
 }
 
-#[derive(FromField)]
+#[derive(FromField, Debug, Clone)]
 pub struct BaseField {
     pub ident: Option<syn::Ident>,
     pub ty: syn::Type,
 }
 
-#[derive(FromVariant)]
+#[derive(FromVariant, Debug, Clone)]
 pub struct BaseVariant<F: FromField> {
     pub ident: syn::Ident,
     pub fields: darling::ast::Fields<F>,
 }
+
+impl<F: FromField + SetContext> SetContext for BaseVariant<F> {
+    type Context = F::Context;
+
+    fn set_context(&mut self, context: Self::Context) {
+        self.fields.set_context(context);
+    }
+}
+
+impl SetContext for BaseField {
+    type Context = Ignored;
+
+    fn set_context(&mut self, _: Self::Context) {}
+}
@@ -9,6 +9,7 @@ use crate::utils::with_context::SetContext;
 #[derive(Debug, Clone)]
 pub struct BaseStruct<F: FromField, G: FromGenerics = ()> {
     pub ident: syn::Ident,
+    pub vis: syn::Visibility,
     pub generics: G,
     pub data: darling::ast::Fields<F>,
 }
@@ -26,6 +27,7 @@ where
             }
             darling::ast::Data::Struct(data) => Ok(BaseStruct {
                 ident: base.ident,
+                vis: base.vis,
                 generics: base.generics,
                 data,
             }),
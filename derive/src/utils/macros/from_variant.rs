@@ -9,6 +9,22 @@ macro_rules! from_variant {
 
         impl_from!(darling::FromVariant, from_variant, syn::Variant, $name,);
     };
+    ($name:ident, $ty:ty, inner=$( $field_path:ident ).+,) => {
+        from_variant!($name, $ty, inner=$( $field_path ).+);
+    };
+    ($name:ident, $ty:ty, inner=$( $field_path:ident ).+) => {
+        crate::utils::macros::define!($name, $ty);
+        crate::utils::macros::deref!($name, $ty);
+        crate::utils::macros::set_context!($name, $ty, inner=$( $field_path ).+);
+
+        impl_from!(
+            darling::FromVariant,
+            from_variant,
+            syn::Variant,
+            $name,
+            inner=$( $field_path ).+,
+        );
+    };
 }
 
 pub(crate) use from_variant;
@@ -0,0 +1,23 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::utils::crate_name::get_crate_name;
+
+#[derive(Debug, Copy, Clone, FromMeta)]
+pub enum MapAs {
+    #[darling(rename = "entries")]
+    Entries,
+    #[darling(rename = "json")]
+    Json,
+}
+
+impl MapAs {
+    pub fn get_repr(&self) -> TokenStream {
+        let crate_name = get_crate_name();
+        match self {
+            Self::Entries => quote!(#crate_name::internal::AsEntries),
+            Self::Json => quote!(#crate_name::internal::AsJson),
+        }
+    }
+}
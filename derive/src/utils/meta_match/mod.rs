@@ -2,13 +2,10 @@ pub use match_meta_path::MatchMetaPath;
 pub use match_nested_meta::MatchNestedMeta;
 pub use match_nested_meta_list::MatchNestedMetaList;
 pub use match_path::MatchPath;
-pub use match_string::MatchString;
 
 mod match_nested_meta;
 mod match_nested_meta_list;
 mod match_path;
-mod match_string;
 
-mod match_lit_str;
 mod match_meta_path;
 mod match_nested_meta_tuple;
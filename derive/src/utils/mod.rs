@@ -8,12 +8,12 @@ pub mod error;
 pub mod impl_block;
 pub mod interface_attr;
 pub mod macros;
+pub mod map_as;
 pub mod meta_match;
 pub mod path_attr;
 pub mod register_attr;
 pub mod rename_rule;
 pub mod type_utils;
-pub mod with_arg;
 pub mod with_attributes;
 pub mod with_context;
 pub mod with_doc;
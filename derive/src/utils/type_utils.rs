@@ -82,3 +82,43 @@ pub fn get_value_type(ty: &syn::Type) -> Option<TokenStream> {
     }
     None
 }
+
+/// The `T` of an `Option<T>` type, matched by the last path segment
+pub fn get_option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// The `T` and `E` of a `Result<T, E>` type, matched by the last path segment
+pub fn get_result_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match (args.args.first(), args.args.get(1)) {
+        (Some(syn::GenericArgument::Type(ok)), Some(syn::GenericArgument::Type(err)))
+            if args.args.len() == 2 =>
+        {
+            Some((ok, err))
+        }
+        _ => None,
+    }
+}
@@ -1,4 +1,5 @@
 mod schema_utils;
 mod gql_enum {
+    mod other_tests;
     mod tests;
 }
@@ -0,0 +1,169 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Enum;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+mod remote {
+    #[non_exhaustive]
+    pub enum Status {
+        Active,
+        Inactive,
+        Banned,
+    }
+}
+
+#[derive(Enum)]
+#[graphql(remote(remote::Status))]
+enum Status {
+    Active,
+    Inactive,
+    #[graphql(other)]
+    Unknown,
+}
+
+#[derive(Enum)]
+#[graphql(remote(remote::Status), fallback(access_fallback))]
+enum Access {
+    Active,
+    Inactive,
+}
+
+fn access_fallback(value: remote::Status) -> Access {
+    match value {
+        remote::Status::Banned => Access::Inactive,
+        _ => Access::Active,
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn status(&self) -> Status {
+        remote::Status::Banned.into()
+    }
+    fn access(&self) -> Access {
+        remote::Status::Banned.into()
+    }
+    fn is_active(&self, status: Status) -> dynamic_graphql::Result<bool> {
+        let status: remote::Status = status.try_into()?;
+        Ok(matches!(status, remote::Status::Active))
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[test]
+fn test_remote_conversions() {
+    assert!(matches!(remote::Status::Active.into(), Status::Active));
+    assert!(matches!(remote::Status::Banned.into(), Status::Unknown));
+    assert!(matches!(remote::Status::Banned.into(), Access::Inactive));
+
+    let status: Result<remote::Status, _> = Status::Inactive.try_into();
+    assert!(matches!(status, Ok(remote::Status::Inactive)));
+
+    let status: Result<remote::Status, _> = Status::Unknown.try_into();
+    insta::assert_snapshot!(status.err().unwrap().message, @r"
+    Variant `Unknown` of enum `Status` has no remote equivalent
+    ");
+}
+
+#[test]
+fn test_value_conversions() {
+    let value = dynamic_graphql::Value::try_from(&Status::Active);
+    assert_eq!(
+        value.unwrap(),
+        dynamic_graphql::Value::Enum(dynamic_graphql::Name::new("ACTIVE"))
+    );
+
+    let value = dynamic_graphql::Value::try_from(&Status::Unknown);
+    insta::assert_snapshot!(value.err().unwrap().message, @r"
+    Variant `Unknown` of enum `Status` has no GraphQL value
+    ");
+}
+
+#[tokio::test]
+async fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    enum Access {
+      ACTIVE
+      INACTIVE
+    }
+
+    type Query {
+      status: Status!
+      access: Access!
+      isActive(status: Status!): Boolean!
+    }
+
+    enum Status {
+      ACTIVE
+      INACTIVE
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            access
+            active: isActive(status: ACTIVE)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "access": "INACTIVE",
+            "active": true,
+        })
+    );
+
+    let query = r#"
+        query {
+            status
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r"
+    Variant `Unknown` of enum `Status` has no GraphQL value
+    ");
+
+    let query = r#"
+        query {
+            isActive(status: UNKNOWN)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "status", enumeration type "Status" does not contain the value "UNKNOWN"
+    "#);
+}
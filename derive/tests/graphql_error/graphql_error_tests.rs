@@ -0,0 +1,92 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::GraphQLError;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::InputValueError;
+
+#[derive(GraphQLError, Debug)]
+enum MyError {
+    #[graphql(code = "NOT_FOUND", message = "User {id} not found")]
+    NotFound {
+        id: String,
+        #[graphql(skip)]
+        internal: String,
+    },
+    #[graphql(message = "Access denied")]
+    Forbidden,
+    #[graphql(code = "BAD_INPUT", message = "Invalid value {0}, expected {{{1}}}")]
+    InvalidValue(#[graphql(name = "value")] String, String),
+}
+
+#[tokio::test]
+async fn test_query() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn user(id: String) -> Result<String, MyError> {
+            Err(MyError::NotFound {
+                id,
+                internal: "lookup failed".to_string(),
+            })
+        }
+        fn admin() -> Result<String, MyError> {
+            Err(MyError::Forbidden)
+        }
+        fn parse(value: String) -> Result<String, MyError> {
+            Err(MyError::InvalidValue(value, "number".to_string()))
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            user(id: "1")
+            admin
+            parse(value: "abc")
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let errors = serde_json::to_value(&res.errors).unwrap();
+    assert_eq!(
+        errors,
+        serde_json::json!([
+            {
+                "message": "User 1 not found",
+                "locations": [{ "line": 3, "column": 13 }],
+                "extensions": { "code": "NOT_FOUND", "id": "1" },
+            },
+            {
+                "message": "Access denied",
+                "locations": [{ "line": 4, "column": 13 }],
+                "extensions": { "code": "FORBIDDEN" },
+            },
+            {
+                "message": "Invalid value abc, expected {number}",
+                "locations": [{ "line": 5, "column": 13 }],
+                "extensions": { "code": "BAD_INPUT", "value": "abc" },
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_input_value_error() {
+    let error: InputValueError<String> = MyError::Forbidden.into();
+    let error = error.into_arg_error("role");
+    assert_eq!(
+        error.message,
+        r#"Invalid value for argument "role": Failed to parse "String": Access denied"#
+    );
+    let extensions = serde_json::to_value(&error.extensions).unwrap();
+    assert_eq!(extensions, serde_json::json!({ "code": "FORBIDDEN" }));
+}
@@ -1,6 +1,12 @@
 mod schema_utils;
 mod input_object {
+    mod expand_input_object_tests;
+    mod filter_input_tests;
+    mod flatten_tests;
     mod list_tests;
+    mod order_by_input_tests;
+    mod patch_input_tests;
     mod tests;
     mod type_tests;
+    mod with_generics_tests;
 }
@@ -0,0 +1,154 @@
+use dynamic_graphql::App;
+use dynamic_graphql::ExpandInputObject;
+use dynamic_graphql::ExpandedInput;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(InputObject)]
+struct CreateOrderInput {
+    product: String,
+    quantity: i32,
+}
+
+// contributed by the loyalty module
+#[derive(ExpandInputObject)]
+#[graphql(target(CreateOrderInput))]
+struct LoyaltyOrderInput {
+    /// the loyalty card of the customer
+    loyalty_code: Option<String>,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn create_order(input: ExpandedInput<CreateOrderInput>) -> dynamic_graphql::Result<String> {
+        let loyalty = input.expansion::<LoyaltyOrderInput>()?;
+        Ok(format!(
+            "{} x{} {:?}",
+            input.product, input.quantity, loyalty.loyalty_code
+        ))
+    }
+}
+
+#[derive(App)]
+struct App(Query, LoyaltyOrderInput);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    input CreateOrderInput {
+      product: String!
+      quantity: Int!
+      "the loyalty card of the customer" loyaltyCode: String
+    }
+
+    type Query {
+      createOrder(input: CreateOrderInput!): String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            withCode: createOrder(input: { product: "book", quantity: 2, loyaltyCode: "GOLD" })
+            withoutCode: createOrder(input: { product: "pen", quantity: 1 })
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "withCode": "book x2 Some(\"GOLD\")",
+            "withoutCode": "pen x1 None",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_expansion_per_schema() {
+    #[derive(App)]
+    struct OtherApp(Query);
+
+    // the expansion registered by `App` doesn't leak into other schemas
+    let _ = App::create_schema().finish().unwrap();
+    let schema = OtherApp::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            createOrder(input: { product: "book", quantity: 2 })
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        format!(
+            "Input expansion `{}` is not registered.",
+            std::any::type_name::<LoyaltyOrderInput>()
+        )
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Field \"quantity\" of \"CreateOrderInput\" is defined more than once, check the expansion BulkOrderInput"
+)]
+fn test_field_collision() {
+    #[allow(dead_code)]
+    #[derive(InputObject)]
+    struct CreateOrderInput {
+        product: String,
+        quantity: i32,
+    }
+
+    #[allow(dead_code)]
+    #[derive(ExpandInputObject)]
+    #[graphql(target(CreateOrderInput))]
+    struct BulkOrderInput {
+        quantity: i64,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn create_order(input: ExpandedInput<CreateOrderInput>) -> String {
+            input.product.clone()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query, BulkOrderInput);
+
+    let _ = App::create_schema().finish();
+}
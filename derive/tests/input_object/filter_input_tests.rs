@@ -0,0 +1,307 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::FilterInput;
+use dynamic_graphql::FilterOp;
+use dynamic_graphql::FilterPredicate;
+use dynamic_graphql::IntFilter;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::StringFilter;
+use dynamic_graphql::Value;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject, FilterInput, Clone)]
+struct Address {
+    city: String,
+    zip: Option<String>,
+}
+
+/// A registered user
+#[derive(SimpleObject, FilterInput, Clone)]
+struct User {
+    name: String,
+    /// age in years
+    age: i32,
+    score: f64,
+    #[graphql(name = "isActive")]
+    active: bool,
+    address: Address,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+fn users() -> Vec<User> {
+    let user = |name: &str, age, city: &str| User {
+        name: name.to_string(),
+        age,
+        score: age as f64 / 10.0,
+        active: age < 40,
+        address: Address {
+            city: city.to_string(),
+            zip: None,
+        },
+    };
+    vec![
+        user("Alice", 30, "Paris"),
+        user("Bob", 45, "Berlin"),
+        user("Carol", 25, "Paris"),
+    ]
+}
+
+#[ResolvedObjectFields]
+impl Query {
+    fn users(&self, filter: Option<UserFilter>) -> Vec<User> {
+        users()
+            .into_iter()
+            .filter(|user| filter.as_ref().is_none_or(|filter| filter.matches(user)))
+            .collect()
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Address {
+      city: String!
+      zip: String
+    }
+
+    input AddressFilter {
+      city: StringFilter
+      zip: StringFilter
+      and: [AddressFilter!]
+      or: [AddressFilter!]
+      not: AddressFilter
+    }
+
+    input BooleanFilter {
+      eq: Boolean
+      ne: Boolean
+    }
+
+    input FloatFilter {
+      eq: Float
+      ne: Float
+      lt: Float
+      lte: Float
+      gt: Float
+      gte: Float
+    }
+
+    input IntFilter {
+      eq: Int
+      ne: Int
+      in: [Int!]
+      lt: Int
+      lte: Int
+      gt: Int
+      gte: Int
+    }
+
+    type Query {
+      users(filter: UserFilter): [User!]!
+    }
+
+    input StringFilter {
+      eq: String
+      ne: String
+      in: [String!]
+      contains: String
+      startsWith: String
+      endsWith: String
+    }
+
+    "A registered user"
+    type User {
+      name: String!
+      "age in years"
+      age: Int!
+      score: Float!
+      isActive: Boolean!
+      address: Address!
+    }
+
+    input UserFilter {
+      name: StringFilter
+      "age in years" age: IntFilter
+      score: FloatFilter
+      isActive: BooleanFilter
+      address: AddressFilter
+      and: [UserFilter!]
+      or: [UserFilter!]
+      not: UserFilter
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            adults: users(filter: { age: { gte: 30 } }) { name }
+            names: users(filter: { name: { in: ["Alice", "Bob"] }, isActive: { eq: true } }) { name }
+            either: users(filter: { or: [{ name: { startsWith: "C" } }, { age: { gt: 40 } }] }) { name }
+            not: users(filter: { not: { name: { contains: "o" } } }) { name }
+            paris: users(filter: { address: { city: { eq: "Paris" } } }) { name }
+            all: users { name }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "adults": [{ "name": "Alice" }, { "name": "Bob" }],
+            "names": [{ "name": "Alice" }],
+            "either": [{ "name": "Bob" }, { "name": "Carol" }],
+            "not": [{ "name": "Alice" }],
+            "paris": [{ "name": "Alice" }, { "name": "Carol" }],
+            "all": [{ "name": "Alice" }, { "name": "Bob" }, { "name": "Carol" }],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_operator_value() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query($filter: UserFilter) {
+            users(filter: $filter) { name }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_json(serde_json::json!({
+            "filter": { "age": { "lt": "ten" } }
+        })))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "filter.age.lt", expected type "Int"
+    "#);
+}
+
+#[test]
+fn test_nested_filter() {
+    #[derive(FilterInput)]
+    struct Customer {
+        name: String,
+        address: Address,
+    }
+
+    let filter = CustomerFilter {
+        address: Some(AddressFilter {
+            city: Some(StringFilter {
+                eq: Some("Paris".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let customer = |city: &str| Customer {
+        name: "Alice".to_string(),
+        address: Address {
+            city: city.to_string(),
+            zip: None,
+        },
+    };
+    assert!(filter.matches(&customer("Paris")));
+    assert!(!filter.matches(&customer("Berlin")));
+
+    let filter = AddressFilter {
+        zip: Some(StringFilter {
+            eq: Some("75001".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(!filter.matches(&customer("Paris").address));
+    assert!(AddressFilter::default().matches(&customer("Paris").address));
+}
+
+#[test]
+fn test_to_predicate() {
+    let filter = UserFilter {
+        age: Some(IntFilter {
+            gte: Some(18),
+            lt: Some(65),
+            ..Default::default()
+        }),
+        not: Some(Box::new(UserFilter {
+            name: Some(StringFilter {
+                eq: Some("Bob".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        filter.to_predicate(),
+        FilterPredicate::And(vec![
+            FilterPredicate::And(vec![
+                FilterPredicate::Compare {
+                    field: "age".to_string(),
+                    op: FilterOp::Lt,
+                    value: Value::from(65),
+                },
+                FilterPredicate::Compare {
+                    field: "age".to_string(),
+                    op: FilterOp::Gte,
+                    value: Value::from(18),
+                },
+            ]),
+            FilterPredicate::Not(Box::new(FilterPredicate::Compare {
+                field: "name".to_string(),
+                op: FilterOp::Eq,
+                value: Value::from("Bob"),
+            })),
+        ])
+    );
+
+    let filter = AddressFilter {
+        city: Some(StringFilter {
+            ends_with: Some("is".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        filter.to_predicate(),
+        FilterPredicate::Compare {
+            field: "city".to_string(),
+            op: FilterOp::EndsWith,
+            value: Value::from("is"),
+        }
+    );
+}
@@ -0,0 +1,119 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(InputObject)]
+struct Pagination {
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+#[tokio::test]
+async fn test_flatten() {
+    #[derive(InputObject)]
+    struct SearchInput {
+        query: String,
+        #[graphql(flatten)]
+        pagination: Pagination,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn search(&self, input: SearchInput) -> String {
+            format!(
+                "{} {:?} {:?}",
+                input.query, input.pagination.limit, input.pagination.offset
+            )
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      search(input: SearchInput!): String!
+    }
+
+    input SearchInput {
+      query: String!
+      limit: Int
+      offset: Int
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            search(input: { query: "foo", limit: 10 })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(data, serde_json::json!({ "search": "foo Some(10) None" }));
+
+    let query = r#"
+        query {
+            search(input: { query: "foo", limit: "ten" })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "input.limit", expected type "Int"
+    "#);
+}
+
+#[test]
+#[should_panic(expected = "Field \"limit\" of \"SearchInput\" is defined more than once")]
+fn test_flatten_name_collision() {
+    #[allow(dead_code)]
+    #[derive(InputObject)]
+    struct SearchInput {
+        limit: i32,
+        #[graphql(flatten)]
+        pagination: Pagination,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn search(&self, input: SearchInput) -> i32 {
+            input.limit
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let _ = App::create_schema().finish();
+}
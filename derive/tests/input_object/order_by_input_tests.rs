@@ -0,0 +1,172 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::OrderBy;
+use dynamic_graphql::OrderByInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::SortDirection;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject, OrderByInput, Clone)]
+struct User {
+    name: String,
+    /// age in years
+    age: i32,
+    score: Option<f64>,
+    #[graphql(skip)]
+    tags: Vec<String>,
+}
+
+fn users() -> Vec<User> {
+    let user = |name: &str, age, score| User {
+        name: name.to_string(),
+        age,
+        score,
+        tags: vec![],
+    };
+    vec![
+        user("Carol", 30, Some(4.5)),
+        user("Alice", 30, None),
+        user("Bob", 25, Some(3.0)),
+    ]
+}
+
+fn names(users: &[User]) -> Vec<&str> {
+    users.iter().map(|user| user.name.as_str()).collect()
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn users(&self, order_by: Option<Vec<UserOrderBy>>) -> Vec<User> {
+        let mut users = users();
+        order_by.sort(&mut users);
+        users
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      users(orderBy: [UserOrderBy!]): [User!]!
+    }
+
+    enum SortDirection {
+      ASC
+      DESC
+    }
+
+    type User {
+      name: String!
+      "age in years"
+      age: Int!
+      score: Float
+    }
+
+    input UserOrderBy {
+      field: UserOrderField!
+      direction: SortDirection = ASC
+    }
+
+    enum UserOrderField {
+      NAME
+      "age in years" AGE
+      SCORE
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            byName: users(orderBy: [{ field: NAME }]) { name }
+            byAgeDesc: users(orderBy: [{ field: AGE, direction: DESC }, { field: NAME }]) { name }
+            byScore: users(orderBy: [{ field: SCORE, direction: DESC }]) { name }
+            unordered: users { name }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "byName": [{ "name": "Alice" }, { "name": "Bob" }, { "name": "Carol" }],
+            "byAgeDesc": [{ "name": "Alice" }, { "name": "Carol" }, { "name": "Bob" }],
+            "byScore": [{ "name": "Carol" }, { "name": "Bob" }, { "name": "Alice" }],
+            "unordered": [{ "name": "Carol" }, { "name": "Alice" }, { "name": "Bob" }],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_field() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query($orderBy: [UserOrderBy!]) {
+            users(orderBy: $orderBy) { name }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_json(serde_json::json!({
+            "orderBy": [{ "field": "TAGS" }]
+        })))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "orderBy.0.field", enumeration type "UserOrderField" does not contain the value "TAGS"
+    "#);
+}
+
+#[test]
+fn test_sort() {
+    let mut users = users();
+
+    let order_by = [
+        UserOrderBy {
+            field: UserOrderField::Age,
+            direction: SortDirection::Asc,
+        },
+        UserOrderBy {
+            field: UserOrderField::Score,
+            direction: SortDirection::Desc,
+        },
+    ];
+    order_by.as_slice().sort(&mut users);
+    assert_eq!(names(&users), ["Bob", "Carol", "Alice"]);
+
+    users.sort_by(|a, b| UserOrderField::Name.compare(b, a));
+    assert_eq!(names(&users), ["Carol", "Bob", "Alice"]);
+    assert!(users.iter().all(|user| user.tags.is_empty()));
+}
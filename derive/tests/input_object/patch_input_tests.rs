@@ -0,0 +1,167 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::MaybeUndefined;
+use dynamic_graphql::MutationFields;
+use dynamic_graphql::MutationRoot;
+use dynamic_graphql::PatchInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject, PatchInput, Clone)]
+#[graphql(name = "Person")]
+struct User {
+    /// display name
+    name: String,
+    age: i32,
+    nickname: Option<String>,
+    #[graphql(skip)]
+    version: u32,
+}
+
+fn user() -> User {
+    User {
+        name: "Jane".to_string(),
+        age: 30,
+        nickname: Some("JJ".to_string()),
+        version: 1,
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn user(&self) -> User {
+        user()
+    }
+}
+
+#[derive(MutationRoot)]
+struct MutationRoot;
+
+#[derive(dynamic_graphql::Mutation)]
+struct UpdateUser(MutationRoot);
+
+#[MutationFields]
+impl UpdateUser {
+    fn update_user(patch: UserPatch) -> User {
+        let mut user = user();
+        patch.apply(&mut user);
+        user
+    }
+}
+
+#[derive(App)]
+struct App(Query, MutationRoot, UpdateUser);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type MutationRoot {
+      updateUser(patch: PersonPatch!): Person!
+    }
+
+    type Person {
+      "display name"
+      name: String!
+      age: Int!
+      nickname: String
+    }
+
+    input PersonPatch {
+      "display name" name: String
+      age: Int
+      nickname: String
+    }
+
+    type Query {
+      user: Person!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+      mutation: MutationRoot
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_apply() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        mutation {
+            rename: updateUser(patch: { name: "Joan" }) { name age nickname }
+            clear: updateUser(patch: { age: 31, nickname: null }) { name age nickname }
+            noop: updateUser(patch: {}) { name age nickname }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "rename": { "name": "Joan", "age": 30, "nickname": "JJ" },
+            "clear": { "name": "Jane", "age": 31, "nickname": null },
+            "noop": { "name": "Jane", "age": 30, "nickname": "JJ" },
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_null_non_optional_field() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        mutation {
+            updateUser(patch: { name: null }) { name }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "patch": Failed to parse "PersonPatch": Invalid value for field "name": null is not allowed for a non-optional field
+    "#);
+}
+
+#[test]
+fn test_apply_in_rust() {
+    let patch = UserPatch {
+        nickname: MaybeUndefined::Value("Jay".to_string()),
+        ..Default::default()
+    };
+    let mut user = user();
+    patch.apply(&mut user);
+    assert_eq!(user.name, "Jane");
+    assert_eq!(user.nickname.as_deref(), Some("Jay"));
+    assert_eq!(user.version, 1);
+
+    let patch = UserPatch {
+        name: MaybeUndefined::Value("Joan".to_string()),
+        nickname: MaybeUndefined::Null,
+        ..Default::default()
+    };
+    patch.apply(&mut user);
+    assert_eq!(user.name, "Joan");
+    assert_eq!(user.nickname, None);
+}
@@ -0,0 +1,155 @@
+use std::borrow::Cow;
+
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::FromValue;
+use dynamic_graphql::internal::InputTypeName;
+use dynamic_graphql::internal::TypeName;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_generic_input_object() {
+    #[derive(InputObject)]
+    struct Page<T>
+    where
+        T: InputTypeName + FromValue + 'static,
+    {
+        items: Vec<T>,
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn count(&self, page: Page<i32>) -> usize {
+            page.items.len()
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    input Page {
+      items: [Int!]!
+    }
+
+    type Query {
+      count(page: Page!): Int!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            count(page: { items: [1, 2, 3] })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(data, serde_json::json!({ "count": 3 }));
+}
+
+#[tokio::test]
+async fn test_generic_input_object_with_type_name() {
+    #[derive(InputObject)]
+    #[graphql(get_type_name)]
+    struct Filter<T>
+    where
+        T: InputTypeName + FromValue + 'static,
+    {
+        eq: Option<T>,
+        one_of: Option<Vec<T>>,
+    }
+
+    impl<T> TypeName for Filter<T>
+    where
+        T: InputTypeName + FromValue + 'static,
+    {
+        fn get_type_name() -> Cow<'static, str> {
+            format!("{}Filter", T::get_type_name()).into()
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn by_number(&self, filter: Filter<i32>) -> Option<i32> {
+            filter.eq.or_else(|| filter.one_of?.first().copied())
+        }
+        fn by_name(&self, filter: Filter<String>) -> Option<String> {
+            filter.eq
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    input IntFilter {
+      eq: Int
+      oneOf: [Int!]
+    }
+
+    type Query {
+      byNumber(filter: IntFilter!): Int
+      byName(filter: StringFilter!): String
+    }
+
+    input StringFilter {
+      eq: String
+      oneOf: [String!]
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            byNumber(filter: { oneOf: [4, 5] })
+            byName(filter: { eq: "foo" })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(data, serde_json::json!({ "byNumber": 4, "byName": "foo" }));
+}
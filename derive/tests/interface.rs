@@ -2,6 +2,7 @@ mod schema_utils;
 mod interface {
     mod as_value_tests;
     mod async_test;
+    mod expand_interface_tests;
     mod implementation_tests;
     mod interface_tests;
     mod list_tests;
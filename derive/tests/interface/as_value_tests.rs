@@ -34,7 +34,7 @@ async fn interface_as_output_value_for_simple_object_with_implement() {
 
     #[ResolvedObjectFields]
     impl Query {
-        async fn node(&self) -> Instance<dyn Node> {
+        async fn node(&self) -> Instance<'_, dyn Node> {
             Instance::new_owned(FooNode {
                 other_field: "foo".to_string(),
             })
@@ -116,7 +116,7 @@ async fn interface_as_output_value_for_simple_object_with_mark_with() {
 
     #[ResolvedObjectFields]
     impl Query {
-        async fn node(&self) -> Instance<dyn Node> {
+        async fn node(&self) -> Instance<'_, dyn Node> {
             Instance::new_owned(FooNode {
                 the_id: "foo".to_string(),
                 other_field: "foo".to_string(),
@@ -209,7 +209,7 @@ async fn interface_as_output_value_for_resolved_object_with_implement() {
 
     #[ResolvedObjectFields]
     impl Query {
-        async fn node(&self) -> Instance<dyn Node> {
+        async fn node(&self) -> Instance<'_, dyn Node> {
             Instance::new_owned(FooNode)
         }
     }
@@ -296,7 +296,7 @@ async fn interface_as_output_value_for_resolved_object_with_mark_with() {
 
     #[ResolvedObjectFields]
     impl Query {
-        async fn node(&self) -> Instance<dyn Node> {
+        async fn node(&self) -> Instance<'_, dyn Node> {
             Instance::new_owned(FooNode)
         }
     }
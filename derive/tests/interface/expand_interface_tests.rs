@@ -0,0 +1,178 @@
+use dynamic_graphql::App;
+use dynamic_graphql::ExpandInterface;
+use dynamic_graphql::ExpandInterfaceFields;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Instance;
+use dynamic_graphql::Interface;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[Interface]
+trait Node {
+    fn id(&self) -> String;
+}
+
+#[derive(SimpleObject)]
+#[graphql(implements(Node))]
+struct User {
+    name: String,
+}
+
+impl Node for User {
+    fn id(&self) -> String {
+        format!("user:{}", self.name)
+    }
+}
+
+#[derive(SimpleObject)]
+#[graphql(implements(Node))]
+struct Post {
+    title: String,
+}
+
+impl Node for Post {
+    fn id(&self) -> String {
+        format!("post:{}", self.title)
+    }
+}
+
+#[derive(ExpandInterface)]
+struct NodeAudit<'a>(&'a dyn Node);
+
+#[ExpandInterfaceFields]
+impl NodeAudit<'_> {
+    /// the changes of the node
+    fn audit_log(&self, limit: i32) -> Vec<String> {
+        (1..=limit)
+            .map(|version| format!("{}@{}", self.0.id(), version))
+            .collect()
+    }
+
+    fn audited() -> bool {
+        true
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn nodes(&self) -> Vec<Instance<'static, dyn Node>> {
+        vec![
+            Instance::new_owned(User {
+                name: "alice".to_string(),
+            }),
+            Instance::new_owned(Post {
+                title: "hello".to_string(),
+            }),
+        ]
+    }
+}
+
+#[derive(App)]
+struct App(Query, NodeAudit<'static>, User, Post);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    interface Node {
+      id: String!
+      "the changes of the node"
+      auditLog(limit: Int!): [String!]!
+      audited: Boolean!
+    }
+
+    type Post implements Node {
+      title: String!
+      id: String!
+      "the changes of the node"
+      auditLog(limit: Int!): [String!]!
+      audited: Boolean!
+    }
+
+    type Query {
+      nodes: [Node!]!
+    }
+
+    type User implements Node {
+      name: String!
+      id: String!
+      "the changes of the node"
+      auditLog(limit: Int!): [String!]!
+      audited: Boolean!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            nodes {
+                id
+                auditLog(limit: 2)
+                ... on User { name audited }
+                ... on Post { title }
+            }
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "nodes": [
+                {
+                    "id": "user:alice",
+                    "auditLog": ["user:alice@1", "user:alice@2"],
+                    "name": "alice",
+                    "audited": true,
+                },
+                {
+                    "id": "post:hello",
+                    "auditLog": ["post:hello@1", "post:hello@2"],
+                    "title": "hello",
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Object \"Marked\" is marked with interface \"Node\" and can't resolve the fields of NodeAudit, use `implements` instead of `mark`"
+)]
+fn test_marked_implementer() {
+    #[derive(SimpleObject)]
+    #[graphql(mark(Node))]
+    struct Marked {
+        id: String,
+    }
+
+    #[derive(App)]
+    struct App(Query, NodeAudit<'static>, User, Post, Marked);
+
+    let _ = App::create_schema().finish();
+}
@@ -429,11 +429,6 @@ async fn test_auto_register_instance() {
         }
     }
 
-    #[derive(SimpleObject)]
-    struct Bar {
-        id: String,
-    }
-
     #[derive(SimpleObject)]
     struct Foo {
         id: String,
@@ -536,12 +531,12 @@ mod in_mod {
 
         #[ResolvedObjectFields]
         impl Query {
-            async fn foo(&self) -> Instance<dyn super::node::Node> {
+            async fn foo(&self) -> Instance<'_, dyn super::node::Node> {
                 Instance::new_owned(Foo {
                     other: "foo".to_string(),
                 })
             }
-            async fn bar(&self) -> Instance<dyn super::node::Node> {
+            async fn bar(&self) -> Instance<'_, dyn super::node::Node> {
                 Instance::new_owned(Bar {
                     id: "bar".to_string(),
                     other: "bar".to_string(),
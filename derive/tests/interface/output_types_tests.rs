@@ -11,6 +11,7 @@ use crate::schema_utils::normalize_schema;
 
 #[tokio::test]
 async fn interface_string_ref_types() {
+    #[allow(clippy::owned_cow)]
     #[Interface]
     trait Node {
         fn id_ref(&self) -> &String;
@@ -48,7 +49,7 @@ async fn interface_string_ref_types() {
 
     #[ResolvedObjectFields]
     impl Query {
-        async fn node(&self) -> Instance<dyn Node> {
+        async fn node(&self) -> Instance<'_, dyn Node> {
             Instance::new_owned(FooNode {
                 other_field: "foo".to_string(),
                 id: "foo id".to_string(),
@@ -169,7 +170,7 @@ async fn interface_object_ref_types() {
 
     #[ResolvedObjectFields]
     impl Query {
-        async fn baz(&self) -> Instance<dyn Baz> {
+        async fn baz(&self) -> Instance<'_, dyn Baz> {
             Instance::new_owned(FooNode {
                 other_field: "foo".to_string(),
                 bar: Bar {
@@ -1,4 +1,5 @@
 mod schema_utils;
 mod mutation {
     mod mutation_tests;
+    mod relay_mutation_tests;
 }
@@ -0,0 +1,185 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Context;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Mutation;
+use dynamic_graphql::MutationFields;
+use dynamic_graphql::MutationRoot;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject, Clone)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn version(&self) -> i32 {
+        1
+    }
+}
+
+#[derive(MutationRoot)]
+struct MutationRoot;
+
+#[derive(Mutation)]
+struct UserMutation(MutationRoot);
+
+struct NextId(i32);
+
+#[MutationFields]
+impl UserMutation {
+    /// Create a new user
+    #[graphql(relay_mutation)]
+    fn create_user(
+        ctx: &Context,
+        name: String,
+        #[graphql(name = "nick")] nickname: Option<String>,
+    ) -> User {
+        User {
+            id: ctx.data_unchecked::<NextId>().0,
+            name: nickname.unwrap_or(name),
+        }
+    }
+
+    #[graphql(relay_mutation, payload_field = "deleted")]
+    async fn delete_user(id: i32) -> dynamic_graphql::Result<bool> {
+        if id < 0 {
+            return Err("invalid id".into());
+        }
+        Ok(id == 1)
+    }
+
+    fn ping() -> String {
+        "pong".to_string()
+    }
+}
+
+#[derive(App)]
+struct App(Query, MutationRoot, UserMutation);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().data(NextId(7)).finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    input CreateUserInput {
+      clientMutationId: String
+      name: String!
+      nick: String
+    }
+
+    type CreateUserPayload {
+      clientMutationId: String
+      user: User!
+    }
+
+    input DeleteUserInput {
+      clientMutationId: String
+      id: Int!
+    }
+
+    type DeleteUserPayload {
+      clientMutationId: String
+      deleted: Boolean!
+    }
+
+    type MutationRoot {
+      "Create a new user"
+      createUser(input: CreateUserInput!): CreateUserPayload!
+      deleteUser(input: DeleteUserInput!): DeleteUserPayload!
+      ping: String!
+    }
+
+    type Query {
+      version: Int!
+    }
+
+    type User {
+      id: Int!
+      name: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+      mutation: MutationRoot
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().data(NextId(7)).finish().unwrap();
+
+    let query = r#"
+        mutation {
+            createUser(input: { name: "Jane", clientMutationId: "abc" }) {
+                clientMutationId
+                user { id name }
+            }
+            renamed: createUser(input: { name: "Jane", nick: "JJ" }) {
+                clientMutationId
+                user { name }
+            }
+            deleteUser(input: { id: 1, clientMutationId: "def" }) {
+                clientMutationId
+                deleted
+            }
+            ping
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "createUser": {
+                "clientMutationId": "abc",
+                "user": { "id": 7, "name": "Jane" },
+            },
+            "renamed": {
+                "clientMutationId": null,
+                "user": { "name": "JJ" },
+            },
+            "deleteUser": { "clientMutationId": "def", "deleted": true },
+            "ping": "pong",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_error() {
+    let schema = App::create_schema().data(NextId(7)).finish().unwrap();
+
+    let query = r#"
+        mutation {
+            deleteUser(input: { id: -1 }) { deleted }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r"
+    invalid id
+    ");
+}
@@ -2,4 +2,6 @@ mod schema_utils;
 mod one_of_input {
     mod list_option;
     mod tests;
+    mod variant_tests;
+    mod with_generics_tests;
 }
@@ -0,0 +1,150 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::OneOfInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(OneOfInput)]
+enum FooInput {
+    Latest,
+    ById(i32),
+    /// Select by an inclusive range
+    ByRange {
+        from: i32,
+        /// Upper bound
+        to: i32,
+        #[graphql(name = "reversed")]
+        rev: Option<bool>,
+    },
+}
+
+#[derive(OneOfInput)]
+#[graphql(rename_fields = "snake_case")]
+enum Selector {
+    AllItems,
+    ByPage { page_size: i32 },
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn foo(&self, by: FooInput) -> String {
+        match by {
+            FooInput::Latest => "latest".to_string(),
+            FooInput::ById(id) => format!("id: {}", id),
+            FooInput::ByRange { from, to, rev } => {
+                format!("range: {}..{} {:?}", from, to, rev)
+            }
+        }
+    }
+    fn select(&self, by: Selector) -> String {
+        match by {
+            Selector::AllItems => "all".to_string(),
+            Selector::ByPage { page_size } => format!("page: {}", page_size),
+        }
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    input FooByRangeInput {
+      from: Int!
+      "Upper bound" to: Int!
+      reversed: Boolean
+    }
+
+    input FooInput @oneOf {
+      latest: Boolean
+      byId: Int
+      "Select by an inclusive range" byRange: FooByRangeInput
+    }
+
+    type Query {
+      foo(by: FooInput!): String!
+      select(by: Selector!): String!
+    }
+
+    input Selector @oneOf {
+      all_items: Boolean
+      by_page: SelectorByPageInput
+    }
+
+    input SelectorByPageInput {
+      page_size: Int!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Indicates that an Input Object is a OneOf Input Object (and thus requires exactly one of its field be provided)"
+    directive @oneOf on INPUT_OBJECT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            latest: foo(by: { latest: true })
+            byId: foo(by: { byId: 3 })
+            byRange: foo(by: { byRange: { from: 1, to: 5, reversed: true } })
+            all: select(by: { all_items: true })
+            page: select(by: { by_page: { page_size: 10 } })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "latest": "latest",
+            "byId": "id: 3",
+            "byRange": "range: 1..5 Some(true)",
+            "all": "all",
+            "page": "page: 10",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_unit_variant_false() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            foo(by: { latest: false })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "by": Failed to parse "FooInput": Only `true` is accepted for field "latest"
+    "#);
+}
@@ -0,0 +1,91 @@
+use std::borrow::Cow;
+
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::OneOfInput;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::FromValue;
+use dynamic_graphql::internal::InputTypeName;
+use dynamic_graphql::internal::TypeName;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_generic_one_of_input() {
+    #[derive(OneOfInput)]
+    #[graphql(get_type_name)]
+    enum Lookup<T>
+    where
+        T: InputTypeName + FromValue + 'static,
+    {
+        Id(i32),
+        Value(T),
+    }
+
+    impl<T> TypeName for Lookup<T>
+    where
+        T: InputTypeName + FromValue + 'static,
+    {
+        fn get_type_name() -> Cow<'static, str> {
+            format!("{}Lookup", T::get_type_name()).into()
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn find(&self, by: Lookup<String>) -> String {
+            match by {
+                Lookup::Id(id) => format!("id: {}", id),
+                Lookup::Value(value) => format!("value: {}", value),
+            }
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      find(by: StringLookup!): String!
+    }
+
+    input StringLookup @oneOf {
+      id: Int
+      value: String
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Indicates that an Input Object is a OneOf Input Object (and thus requires exactly one of its field be provided)"
+    directive @oneOf on INPUT_OBJECT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            find(by: { value: "foo" })
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(data, serde_json::json!({ "find": "value: foo" }));
+}
@@ -2,6 +2,12 @@ use std::borrow::Cow;
 
 use dynamic_graphql::App;
 use dynamic_graphql::Context;
+use dynamic_graphql::DynamicEnum;
+use dynamic_graphql::DynamicEnumItem;
+use dynamic_graphql::DynamicEnumValue;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
 use dynamic_graphql::SimpleObject;
 use dynamic_graphql::dynamic;
 use dynamic_graphql::dynamic::DynamicRequestExt;
@@ -222,3 +228,119 @@ async fn define_custom_type() {
     let res = schema.execute(req).await;
     assert_eq!(res.data, value!({ "foo": { "value": "the foo" } }));
 }
+
+struct TicketStatus;
+
+impl TypeName for TicketStatus {
+    fn get_type_name() -> Cow<'static, str> {
+        "TicketStatus".into()
+    }
+}
+
+// registered at runtime with `register_dynamic_enum`
+impl Register for TicketStatus {}
+
+fn ticket_status_enum() -> DynamicEnum {
+    // e.g. loaded from the tenant configuration
+    DynamicEnum::new("TicketStatus")
+        .description("the statuses of a ticket")
+        .item("OPEN")
+        .item(DynamicEnumItem::new("IN_PROGRESS").description("someone is on it"))
+        .item(DynamicEnumItem::new("WONT_FIX").deprecation(Some("use CLOSED")))
+        .item("CLOSED")
+}
+
+#[tokio::test]
+async fn define_dynamic_enum() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn default_status(&self) -> DynamicEnumValue<TicketStatus> {
+            DynamicEnumValue::new("OPEN")
+        }
+
+        fn describe(&self, ctx: &Context, status: DynamicEnumValue<TicketStatus>) -> String {
+            format!("{} #{:?}", status.as_str(), status.index(ctx))
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let registry = Registry::new()
+        .register::<App>()
+        .register_dynamic_enum(ticket_status_enum())
+        .unwrap();
+    let schema = registry.create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      defaultStatus: TicketStatus!
+      describe(status: TicketStatus!): String!
+    }
+
+    "the statuses of a ticket"
+    enum TicketStatus {
+      OPEN
+      "someone is on it" IN_PROGRESS
+      WONT_FIX @deprecated(reason: "use CLOSED")
+      CLOSED
+    }
+
+    "Marks an element of a GraphQL schema as no longer supported."
+    directive @deprecated(reason: String = "No longer supported") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            defaultStatus
+            describe(status: IN_PROGRESS)
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let result = schema.execute(req).await.into_result().unwrap();
+    assert_eq!(
+        result.data,
+        value!({ "defaultStatus": "OPEN", "describe": "IN_PROGRESS #Some(1)" })
+    );
+}
+
+#[test]
+fn dynamic_enum_validation() {
+    let invalid = [
+        DynamicEnum::new("Ticket Status").item("OPEN"),
+        DynamicEnum::new("__TicketStatus").item("OPEN"),
+        DynamicEnum::new("TicketStatus"),
+        DynamicEnum::new("TicketStatus").item("1_OPEN"),
+        DynamicEnum::new("TicketStatus").item("true"),
+        DynamicEnum::new("TicketStatus").item("OPEN").item("OPEN"),
+    ];
+    let errors: Vec<_> = invalid
+        .into_iter()
+        .map(|dynamic_enum| dynamic_enum.validate().unwrap_err().message)
+        .collect();
+    insta::assert_snapshot!(errors.join("\n"), @r"
+    Enum name `Ticket Status` is not a valid GraphQL name.
+    Enum name `__TicketStatus` is not a valid GraphQL name.
+    Enum `TicketStatus` must define at least one value.
+    Value `1_OPEN` of enum `TicketStatus` is not a valid GraphQL enum value.
+    Value `true` of enum `TicketStatus` is not a valid GraphQL enum value.
+    Value `OPEN` of enum `TicketStatus` is defined more than once.
+    ");
+
+    assert!(ticket_status_enum().validate().is_ok());
+}
@@ -1,9 +1,15 @@
 mod schema_utils;
 mod resolved_object {
     mod resolved_object_args_tests;
+    mod resolved_object_collection_tests;
+    mod resolved_object_errors_as_data_tests;
+    mod resolved_object_flatten_args_tests;
     mod resolved_object_list_args_tests;
     mod resolved_object_list_tests;
+    mod resolved_object_map_tests;
+    mod resolved_object_number_tests;
     mod resolved_object_result_tests;
+    mod resolved_object_selection_tests;
     mod resolved_object_tests;
     mod resolved_object_type_tests;
     mod resolved_object_with_generics_tests;
@@ -0,0 +1,141 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::List;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use smallvec::SmallVec;
+use smallvec::smallvec;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct Foo {
+    value: i32,
+    tags: BTreeSet<String>,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn array(&self) -> [i32; 3] {
+        [1, 2, 3]
+    }
+    fn deque(&self) -> VecDeque<String> {
+        VecDeque::from(["a".to_string(), "b".to_string()])
+    }
+    fn small(&self) -> SmallVec<[i32; 2]> {
+        smallvec![4, 5, 6]
+    }
+    fn foos(&self) -> List<std::vec::IntoIter<Foo>> {
+        let foos = vec![Foo {
+            value: 1,
+            tags: BTreeSet::from(["y".to_string(), "x".to_string()]),
+        }];
+        List(foos.into_iter())
+    }
+    fn unique(&self, values: HashSet<i32>) -> usize {
+        values.len()
+    }
+    fn sorted(&self, values: BTreeSet<i32>) -> Vec<i32> {
+        values.into_iter().collect()
+    }
+    fn sum(&self, point: [i32; 2]) -> i32 {
+        point[0] + point[1]
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Foo {
+      value: Int!
+      tags: [String!]!
+    }
+
+    type Query {
+      array: [Int!]!
+      deque: [String!]!
+      small: [Int!]!
+      foos: [Foo!]!
+      unique(values: [Int!]!): Int!
+      sorted(values: [Int!]!): [Int!]!
+      sum(point: [Int!]!): Int!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            array
+            deque
+            small
+            foos { value tags }
+            unique(values: [1, 2, 2, 3])
+            sorted(values: [3, 1, 2, 1])
+            sum(point: [1, 2])
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "array": [1, 2, 3],
+            "deque": ["a", "b"],
+            "small": [4, 5, 6],
+            "foos": [{ "value": 1, "tags": ["x", "y"] }],
+            "unique": 3,
+            "sorted": [1, 2, 3],
+            "sum": 3,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_array_length() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            sum(point: [1, 2, 3])
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "point": Failed to parse "[Int!]": Expected a list of 2 items, found 3.
+    "#);
+}
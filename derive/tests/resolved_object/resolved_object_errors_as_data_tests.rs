@@ -0,0 +1,248 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Union;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct User {
+    name: String,
+}
+
+#[derive(SimpleObject)]
+struct EmailTaken {
+    email: String,
+}
+
+#[derive(SimpleObject)]
+struct InvalidInput {
+    message: String,
+}
+
+#[derive(Union)]
+enum CreateUserError {
+    EmailTaken(EmailTaken),
+    InvalidInput(InvalidInput),
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    #[graphql(errors_as_data)]
+    async fn create_user(email: String) -> Result<User, CreateUserError> {
+        if email.is_empty() {
+            return Err(CreateUserError::InvalidInput(InvalidInput {
+                message: "email is empty".to_string(),
+            }));
+        }
+        if email == "taken@example.com" {
+            return Err(CreateUserError::EmailTaken(EmailTaken { email }));
+        }
+        Ok(User { name: email })
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    union CreateUserResult = User | EmailTaken | InvalidInput
+
+    type EmailTaken {
+      email: String!
+    }
+
+    type InvalidInput {
+      message: String!
+    }
+
+    type Query {
+      createUser(email: String!): CreateUserResult!
+    }
+
+    type User {
+      name: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+async fn create_user(schema: &dynamic_graphql::dynamic::Schema, email: &str) -> serde_json::Value {
+    let query = r#"
+        query($email: String!) {
+            createUser(email: $email) {
+                __typename
+                ... on User { name }
+                ... on EmailTaken { email }
+                ... on InvalidInput { message }
+            }
+        }
+    "#;
+    let variables = serde_json::json!({ "email": email });
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_json(variables))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    res.data.into_json().unwrap()
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    assert_eq!(
+        create_user(&schema, "user@example.com").await,
+        serde_json::json!({
+            "createUser": { "__typename": "User", "name": "user@example.com" },
+        })
+    );
+    assert_eq!(
+        create_user(&schema, "taken@example.com").await,
+        serde_json::json!({
+            "createUser": { "__typename": "EmailTaken", "email": "taken@example.com" },
+        })
+    );
+    assert_eq!(
+        create_user(&schema, "").await,
+        serde_json::json!({
+            "createUser": { "__typename": "InvalidInput", "message": "email is empty" },
+        })
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Union)]
+enum CreateEmailError {
+    InvalidInput(InvalidInput),
+}
+
+#[test]
+#[should_panic(
+    expected = "Union \"CreateUserResult\" is defined more than once with different members"
+)]
+fn test_result_union_name_collision() {
+    #[derive(ResolvedObject)]
+    struct Admin;
+
+    #[ResolvedObjectFields]
+    impl Admin {
+        #[graphql(errors_as_data)]
+        async fn create_user(email: String) -> Result<EmailTaken, CreateEmailError> {
+            Ok(EmailTaken { email })
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        #[graphql(errors_as_data)]
+        async fn create_user(email: String) -> Result<User, CreateUserError> {
+            Ok(User { name: email })
+        }
+        fn admin(&self) -> Admin {
+            Admin
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let _ = App::create_schema().finish();
+}
+
+#[test]
+fn test_result_union_name() {
+    #[derive(ResolvedObject)]
+    struct Admin;
+
+    #[ResolvedObjectFields]
+    impl Admin {
+        #[graphql(errors_as_data, result_union_name = "AdminCreateUserResult")]
+        async fn create_user(email: String) -> Result<EmailTaken, CreateEmailError> {
+            Ok(EmailTaken { email })
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        #[graphql(errors_as_data)]
+        async fn create_user(email: String) -> Result<User, CreateUserError> {
+            Ok(User { name: email })
+        }
+        fn admin(&self) -> Admin {
+            Admin
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Admin {
+      createUser(email: String!): AdminCreateUserResult!
+    }
+
+    union AdminCreateUserResult = EmailTaken | InvalidInput
+
+    union CreateUserResult = User | EmailTaken | InvalidInput
+
+    type EmailTaken {
+      email: String!
+    }
+
+    type InvalidInput {
+      message: String!
+    }
+
+    type Query {
+      createUser(email: String!): CreateUserResult!
+      admin: Admin!
+    }
+
+    type User {
+      name: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
@@ -0,0 +1,149 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Args;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::InputObject;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(Args)]
+struct PageArgs {
+    /// the number of items to return
+    first: Option<i32>,
+    after: Option<String>,
+}
+
+#[derive(InputObject)]
+struct UserFilter {
+    name: Option<String>,
+}
+
+#[derive(Args)]
+struct UserArgs {
+    #[graphql(flatten)]
+    page: PageArgs,
+    #[graphql(flatten)]
+    filter: UserFilter,
+    #[graphql(skip)]
+    internal: bool,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn users(&self, #[graphql(flatten)] args: UserArgs, order_by: Option<String>) -> String {
+        format!(
+            "first={:?} after={:?} name={:?} internal={} order_by={:?}",
+            args.page.first, args.page.after, args.filter.name, args.internal, order_by
+        )
+    }
+}
+
+#[derive(ExpandObject)]
+struct QueryPosts<'a>(&'a Query);
+
+#[ExpandObjectFields]
+impl QueryPosts<'_> {
+    fn posts(#[graphql(flatten)] page: &PageArgs) -> i32 {
+        page.first.unwrap_or(10)
+    }
+}
+
+#[derive(App)]
+struct App(Query, QueryPosts<'static>);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      users("the number of items to return" first: Int, after: String, name: String, orderBy: String): String!
+      posts("the number of items to return" first: Int, after: String): Int!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            users(first: 5, name: "foo", orderBy: "name")
+            posts(after: "abc")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "users": "first=Some(5) after=None name=Some(\"foo\") internal=false order_by=Some(\"name\")",
+            "posts": 10,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_arg() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query($first: Int) {
+            users(first: $first)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_json(
+            serde_json::json!({ "first": "five" }),
+        ))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "first", expected type "Int"
+    "#);
+}
+
+#[test]
+#[should_panic(expected = "Argument \"first\" of field \"items\" is defined more than once")]
+fn test_conflicting_args() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn items(&self, #[graphql(flatten)] page: PageArgs, first: i32) -> i32 {
+            page.first.unwrap_or(first)
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let _ = App::create_schema().finish();
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(thiserror::Error, Debug)]
+enum MyError {
+    #[error("Not found")]
+    NotFound,
+}
+
+#[tokio::test]
+async fn test_query() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query {
+        scores: BTreeMap<String, f64>,
+        fail: bool,
+    }
+
+    #[ResolvedObjectFields]
+    impl Query {
+        #[graphql(map_as = "entries")]
+        fn scores(&self) -> &BTreeMap<String, f64> {
+            &self.scores
+        }
+        #[graphql(map_as = "entries")]
+        async fn labels(&self) -> Result<BTreeMap<i32, String>, MyError> {
+            if self.fail {
+                return Err(MyError::NotFound);
+            }
+            Ok(BTreeMap::from([(1, "one".to_string())]))
+        }
+        #[graphql(map_as = "json")]
+        fn settings(&self) -> Option<HashMap<String, bool>> {
+            Some(HashMap::from([("enabled".to_string(), true)]))
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    scalar JSON
+
+    type KeyValue_Float {
+      key: String!
+      value: Float!
+    }
+
+    type KeyValue_Int_String {
+      key: Int!
+      value: String!
+    }
+
+    type Query {
+      scores: [KeyValue_Float!]!
+      labels: [KeyValue_Int_String!]!
+      settings: JSON
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            scores { key value }
+            labels { key value }
+            settings
+        }
+    "#;
+
+    let root = Query {
+        scores: BTreeMap::from([("a".to_string(), 1.5)]),
+        fail: false,
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "scores": [ { "key": "a", "value": 1.5 } ],
+            "labels": [ { "key": 1, "value": "one" } ],
+            "settings": { "enabled": true },
+        })
+    );
+
+    let root = Query {
+        scores: BTreeMap::new(),
+        fail: true,
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "Not found");
+}
@@ -0,0 +1,181 @@
+use std::num::NonZeroU32;
+use std::num::Wrapping;
+
+use dynamic_graphql::App;
+use dynamic_graphql::BigInt;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::IntOutputPolicy;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn big(&self, value: i128) -> i128 {
+        value * 2
+    }
+    fn unsigned_big(&self) -> u128 {
+        u128::MAX
+    }
+    fn big_u64(&self, value: BigInt<u64>) -> BigInt<u64> {
+        value
+    }
+    fn non_zero(&self, value: NonZeroU32) -> NonZeroU32 {
+        value
+    }
+    fn letter(&self, value: char) -> char {
+        value.to_ascii_uppercase()
+    }
+    fn wrapping(&self, value: Wrapping<u8>) -> Wrapping<u8> {
+        value + Wrapping(1)
+    }
+    fn large(&self) -> u64 {
+        u64::MAX
+    }
+    fn small(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    scalar BigInt
+
+    type Query {
+      big(value: BigInt!): BigInt!
+      unsignedBig: BigInt!
+      bigU64(value: BigInt!): BigInt!
+      nonZero(value: Int!): Int!
+      letter(value: String!): String!
+      wrapping(value: Int!): Int!
+      large: Int!
+      small: Int!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            big(value: "-85070591730234615865843651857942052864")
+            unsignedBig
+            bigU64(value: 18446744073709551615)
+            nonZero(value: 3)
+            letter(value: "a")
+            wrapping(value: 255)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "big": "-170141183460469231731687303715884105728",
+            "unsignedBig": "340282366920938463463374607431768211455",
+            "bigU64": "18446744073709551615",
+            "nonZero": 3,
+            "letter": "A",
+            "wrapping": 0,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_input() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            nonZero(value: 0)
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "value": Failed to parse "Int": Only non-zero integers are accepted for NonZeroU32.
+    "#);
+
+    let query = r#"
+        query {
+            letter(value: "ab")
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "value": Failed to parse "String": Only strings of exactly one character are accepted for char.
+    "#);
+}
+
+#[tokio::test]
+async fn test_int_output_policy() {
+    let query = r#"
+        query {
+            large
+            small
+        }
+    "#;
+
+    let schema = App::create_schema().finish().unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({ "large": u64::MAX, "small": i64::MIN })
+    );
+
+    let schema = App::create_schema()
+        .data(IntOutputPolicy::Clamp)
+        .finish()
+        .unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({ "large": i32::MAX, "small": i32::MIN })
+    );
+
+    let schema = App::create_schema()
+        .data(IntOutputPolicy::Error)
+        .finish()
+        .unwrap();
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 2);
+    insta::assert_snapshot!(res.errors[0].message, @r"
+    Int cannot represent non 32-bit signed integer value: 18446744073709551615
+    ");
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Lookahead;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SelectedFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::SelectableFields;
+
+#[derive(SimpleObject, Default)]
+struct Address {
+    city: String,
+    street_name: String,
+}
+
+#[derive(SimpleObject, Default)]
+struct User {
+    id: String,
+    first_name: String,
+    #[graphql(name = "mail")]
+    email: String,
+    #[graphql(skip)]
+    #[allow(dead_code)]
+    password: String,
+    #[graphql(flatten)]
+    address: Address,
+}
+
+#[derive(Default, Clone)]
+struct Selected(Arc<Mutex<Vec<String>>>);
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn users(
+        #[graphql(selection)] fields: SelectedFields<User>,
+        #[graphql(data)] selected: &Selected,
+    ) -> Vec<User> {
+        let mut selected = selected.0.lock().unwrap();
+        selected.extend(fields.iter().map(str::to_string));
+        vec![User::default()]
+    }
+
+    fn user(
+        #[graphql(lookahead)] look: Lookahead<'_>,
+        #[graphql(data)] selected: &Selected,
+    ) -> User {
+        let mut selected = selected.0.lock().unwrap();
+        for name in ["id", "firstName", "mail"] {
+            if look.field(name).exists() {
+                selected.push(name.to_string());
+            }
+        }
+        User::default()
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+async fn get_selected(query: &str) -> Vec<String> {
+    let schema = App::create_schema().finish().unwrap();
+    let selected = Selected::default();
+    let req = dynamic_graphql::Request::new(query)
+        .data(selected.clone())
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    selected.0.lock().unwrap().clone()
+}
+
+#[test]
+fn test_selectable_fields() {
+    assert_eq!(
+        User::get_selectable_fields(),
+        vec![
+            ("id", "id"),
+            ("firstName", "first_name"),
+            ("mail", "email"),
+            ("city", "address"),
+            ("streetName", "address"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_selection() {
+    let query = r#"
+        query {
+            users {
+                __typename
+                mail
+                id
+                ... on User { streetName }
+                ...UserFields
+            }
+        }
+        fragment UserFields on User {
+            id
+            city
+        }
+    "#;
+    assert_eq!(get_selected(query).await, vec!["email", "id", "address"]);
+}
+
+#[tokio::test]
+async fn test_lookahead() {
+    let query = r#"
+        query {
+            user {
+                firstName
+                mail
+            }
+        }
+    "#;
+    assert_eq!(get_selected(query).await, vec!["firstName", "mail"]);
+}
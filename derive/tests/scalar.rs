@@ -1,9 +1,11 @@
 mod schema_utils;
 mod scalar {
     mod common;
+    mod external_tests;
     mod input_type_tests;
     mod json;
     mod json_tests;
     mod output_type_tests;
     mod scalar_tests;
+    mod serde_tests;
 }
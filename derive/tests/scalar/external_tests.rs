@@ -0,0 +1,306 @@
+use bigdecimal::BigDecimal;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Utc;
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use time::macros::date;
+use time::macros::datetime;
+use url::Url;
+use uuid::Uuid;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_chrono() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn now(&self) -> DateTime<Utc> {
+            DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+        }
+        fn next_day(&self, date: NaiveDate) -> NaiveDate {
+            date.succ_opt().unwrap()
+        }
+        fn echo(&self, value: NaiveDateTime) -> NaiveDateTime {
+            value
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    scalar DateTime
+
+    scalar NaiveDate
+
+    scalar NaiveDateTime
+
+    type Query {
+      now: DateTime!
+      nextDay(date: NaiveDate!): NaiveDate!
+      echo(value: NaiveDateTime!): NaiveDateTime!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Provides a scalar specification URL for specifying the behavior of custom scalar types."
+    directive @specifiedBy(url: String!) on SCALAR
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            now
+            nextDay(date: "2024-02-28")
+            echo(value: "2024-02-28T10:20:30")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "now": "2023-11-14T22:13:20Z",
+            "nextDay": "2024-02-29",
+            "echo": "2024-02-28T10:20:30",
+        })
+    );
+
+    let query = r#"
+        query {
+            nextDay(date: "tomorrow")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "date": Failed to parse "NaiveDate": input contains invalid characters
+    "#);
+}
+
+#[tokio::test]
+async fn test_time() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn now(&self) -> OffsetDateTime {
+            datetime!(2023-11-14 22:13:20 UTC)
+        }
+        fn date(&self) -> time::Date {
+            date!(2024 - 02 - 29)
+        }
+        fn echo(&self, value: OffsetDateTime) -> OffsetDateTime {
+            value
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    scalar Date
+
+    scalar DateTime
+
+    type Query {
+      now: DateTime!
+      date: Date!
+      echo(value: DateTime!): DateTime!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Provides a scalar specification URL for specifying the behavior of custom scalar types."
+    directive @specifiedBy(url: String!) on SCALAR
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            now
+            date
+            echo(value: "2024-02-28T10:20:30+01:00")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "now": "2023-11-14T22:13:20Z",
+            "date": "2024-02-29",
+            "echo": "2024-02-28T10:20:30+01:00",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_uuid_url_and_decimals() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn id(&self, id: Uuid) -> Uuid {
+            id
+        }
+        fn homepage(&self, url: Url) -> Url {
+            url.join("docs").unwrap()
+        }
+        fn total(&self, a: Decimal, b: Decimal) -> Decimal {
+            a + b
+        }
+        fn big(&self, value: BigDecimal) -> BigDecimal {
+            value * 2
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    scalar BigDecimal
+
+    scalar Decimal
+
+    type Query {
+      id(id: UUID!): UUID!
+      homepage(url: Url!): Url!
+      total(a: Decimal!, b: Decimal!): Decimal!
+      big(value: BigDecimal!): BigDecimal!
+    }
+
+    scalar UUID
+
+    scalar Url
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Provides a scalar specification URL for specifying the behavior of custom scalar types."
+    directive @specifiedBy(url: String!) on SCALAR
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            id(id: "67e55044-10b1-426f-9247-bb680e5fe0c8")
+            homepage(url: "https://example.com/")
+            total(a: "0.1", b: 0.2)
+            big(value: "12345678901234567890.5")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "homepage": "https://example.com/docs",
+            "total": "0.3",
+            "big": "24691357802469135781.0",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_decimal_number_literals() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn decimal(&self, value: Decimal) -> Decimal {
+            value
+        }
+        fn big(&self, value: BigDecimal) -> BigDecimal {
+            value
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            int: decimal(value: 12345678901234567890)
+            float: decimal(value: 1e21)
+            fraction: decimal(value: 1.5e-3)
+            big: big(value: 1e21)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors, vec![]);
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "int": "12345678901234567890",
+            "float": "1000000000000000000000",
+            "fraction": "0.0015",
+            "big": "1e+21",
+        })
+    );
+}
@@ -0,0 +1,178 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::Scalar;
+use dynamic_graphql::ScalarValue;
+use dynamic_graphql::Value;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::value;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Coords {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Scalar, Serialize, Deserialize, Debug, PartialEq)]
+#[graphql(serde)]
+struct Point(Coords);
+
+#[derive(Scalar, Serialize, Deserialize, Debug, PartialEq)]
+#[graphql(serde)]
+struct Email(String);
+
+#[test]
+fn test_scalar_value() {
+    let point = Point(Coords { x: 1, y: 2 });
+    assert_eq!(point.to_value(), value!({ "x": 1, "y": 2 }));
+    assert_eq!(
+        <Point as ScalarValue>::from_value(value!({ "x": 1, "y": 2 })).unwrap(),
+        point
+    );
+    let email = Email("me@example.com".to_string());
+    assert_eq!(email.to_value(), Value::from("me@example.com"));
+    assert_eq!(
+        <Email as ScalarValue>::from_value(Value::from("me@example.com")).unwrap(),
+        email
+    );
+    assert!(<Email as ScalarValue>::from_value(Value::from(1)).is_err());
+}
+
+#[tokio::test]
+async fn test_query() {
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn point(&self) -> Point {
+            Point(Coords { x: 1, y: 2 })
+        }
+        fn move_point(&self, point: Point) -> Point {
+            Point(Coords {
+                x: point.0.x + 1,
+                y: point.0.y + 1,
+            })
+        }
+        fn email(&self, email: Email) -> Email {
+            email
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    scalar Email
+
+    scalar Point
+
+    type Query {
+      point: Point!
+      movePoint(point: Point!): Point!
+      email(email: Email!): Email!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            point
+            movePoint(point: { x: 1, y: 2 })
+            email(email: "me@example.com")
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "point": { "x": 1, "y": 2 },
+            "movePoint": { "x": 2, "y": 3 },
+            "email": "me@example.com",
+        })
+    );
+
+    let query = r#"
+        query {
+            email(email: 1)
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "email": Failed to parse "Email": invalid type: integer `1`, expected a string
+    "#);
+}
+
+#[tokio::test]
+async fn test_serialize_error() {
+    #[derive(Scalar, Deserialize)]
+    #[graphql(serde)]
+    struct Broken(String);
+
+    impl Serialize for Broken {
+        fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom(format!(
+                "can't serialize {}",
+                self.0
+            )))
+        }
+    }
+
+    #[derive(ResolvedObject)]
+    #[graphql(root)]
+    struct Query;
+
+    #[ResolvedObjectFields]
+    impl Query {
+        fn broken(&self) -> Broken {
+            Broken("value".to_string())
+        }
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    assert_eq!(Broken("value".to_string()).to_value(), Value::Null);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            broken
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r"
+    can't serialize value
+    ");
+}
@@ -1,5 +1,6 @@
 mod schema_utils;
 mod schema_data {
+    mod data_args;
     mod node_data;
     mod node_data_auto;
     mod prepare_data;
@@ -0,0 +1,195 @@
+use dynamic_graphql::App;
+use dynamic_graphql::ExpandObject;
+use dynamic_graphql::ExpandObjectFields;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::Instance;
+use dynamic_graphql::Interface;
+use dynamic_graphql::Mutation;
+use dynamic_graphql::MutationFields;
+use dynamic_graphql::MutationRoot;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+use dynamic_graphql::internal::Register;
+use dynamic_graphql::internal::Registry;
+
+use crate::schema_utils::normalize_schema;
+
+/// request data
+struct Greeting(String);
+
+/// schema data, inserted at registration
+#[derive(Default)]
+struct Suffix(String);
+
+struct RegisterSuffix;
+
+impl Register for RegisterSuffix {
+    fn register(mut registry: Registry) -> Registry {
+        let suffix: &mut Suffix = registry.data.get_mut_or_default();
+        suffix.0 = "!".to_string();
+        registry
+    }
+}
+
+#[Interface]
+trait Named {
+    fn name(&self, #[graphql(data)] suffix: &Suffix) -> String;
+}
+
+#[derive(SimpleObject)]
+#[graphql(implements(Named))]
+struct User {
+    #[graphql(skip)]
+    name: String,
+}
+
+impl Named for User {
+    fn name(&self, suffix: &Suffix) -> String {
+        format!("{}{}", self.name, suffix.0)
+    }
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+#[graphql(register(RegisterSuffix))]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn greet(&self, #[graphql(data)] greeting: &Greeting, name: String) -> String {
+        format!("{}, {}", greeting.0, name)
+    }
+
+    fn user(&self) -> Instance<'static, dyn Named> {
+        Instance::new_owned(User {
+            name: "Bob".to_string(),
+        })
+    }
+}
+
+#[derive(ExpandObject)]
+struct QueryGreeting<'a>(&'a Query);
+
+#[ExpandObjectFields]
+impl QueryGreeting<'_> {
+    fn shout(#[graphql(data)] greeting: &Greeting, #[graphql(data)] suffix: &Suffix) -> String {
+        format!("{}{}", greeting.0.to_uppercase(), suffix.0)
+    }
+}
+
+#[derive(MutationRoot)]
+struct MutationRoot;
+
+#[derive(Mutation)]
+struct GreetMutation(MutationRoot);
+
+#[MutationFields]
+impl GreetMutation {
+    fn set_greeting(#[graphql(data)] greeting: &Greeting, value: String) -> String {
+        format!("{} -> {}", greeting.0, value)
+    }
+}
+
+#[derive(App)]
+struct App(
+    Query,
+    QueryGreeting<'static>,
+    User,
+    MutationRoot,
+    GreetMutation,
+);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type MutationRoot {
+      setGreeting(value: String!): String!
+    }
+
+    interface Named {
+      name: String!
+    }
+
+    type Query {
+      greet(name: String!): String!
+      user: Named!
+      shout: String!
+    }
+
+    type User implements Named {
+      name: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+      mutation: MutationRoot
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            greet(name: "Alice")
+            shout
+            user { name }
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query)
+        .data(Greeting("Hello".to_string()))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "greet": "Hello, Alice",
+            "shout": "HELLO!",
+            "user": { "name": "Bob!" },
+        })
+    );
+
+    let query = r#"
+        mutation {
+            setGreeting(value: "Hi")
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query)
+        .data(Greeting("Hello".to_string()))
+        .root_value(FieldValue::owned_any(MutationRoot));
+    let res = schema.execute(req).await;
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({ "setGreeting": "Hello -> Hi" })
+    );
+}
+
+#[tokio::test]
+async fn test_missing_data() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            greet(name: "Alice")
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r"
+    Data `schema_data::schema_data::data_args::Greeting` does not exist.
+    ");
+}
@@ -1,7 +1,12 @@
 mod schema_utils;
 mod simple_object {
+    mod complex_tests;
+    mod flatten_tests;
+    mod input_name_tests;
     mod list_tests;
+    mod map_tests;
     mod object_tests;
+    mod resolve_with_tests;
     mod type_tests;
     mod with_generics_tests;
 }
@@ -0,0 +1,221 @@
+use dynamic_graphql::App;
+use dynamic_graphql::ComplexObjectFields;
+use dynamic_graphql::Context;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct Post {
+    title: String,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+struct User {
+    first_name: String,
+    last_name: String,
+}
+
+#[ComplexObjectFields]
+impl User {
+    async fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+    fn greet(&self, greeting: String) -> String {
+        format!("{}, {}!", greeting, self.first_name)
+    }
+    fn posts(&self, ctx: &Context) -> Vec<Post> {
+        let prefix = ctx.data_unchecked::<String>();
+        vec![Post {
+            title: format!("{} {}", prefix, self.first_name),
+        }]
+    }
+}
+
+#[tokio::test]
+async fn test_complex() {
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        user: User,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema()
+        .data("Hello".to_string())
+        .finish()
+        .unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Post {
+      title: String!
+    }
+
+    type Query {
+      user: User!
+    }
+
+    type User {
+      firstName: String!
+      lastName: String!
+      fullName: String!
+      greet(greeting: String!): String!
+      posts: [Post!]!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            user {
+                firstName
+                lastName
+                fullName
+                greet(greeting: "Hi")
+                posts { title }
+            }
+        }
+    "#;
+
+    let root = Query {
+        user: User {
+            first_name: "Jane".to_string(),
+            last_name: "Doe".to_string(),
+        },
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "user": {
+                "firstName": "Jane",
+                "lastName": "Doe",
+                "fullName": "Jane Doe",
+                "greet": "Hi, Jane!",
+                "posts": [{ "title": "Hello Jane" }],
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_complex_flatten() {
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        id: i32,
+        #[graphql(flatten)]
+        user: User,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema()
+        .data("Hello".to_string())
+        .finish()
+        .unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Post {
+      title: String!
+    }
+
+    type Query {
+      id: Int!
+      firstName: String!
+      lastName: String!
+      fullName: String!
+      greet(greeting: String!): String!
+      posts: [Post!]!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            id
+            firstName
+            fullName
+            greet(greeting: "Hey")
+        }
+    "#;
+
+    let root = Query {
+        id: 1,
+        user: User {
+            first_name: "John".to_string(),
+            last_name: "Smith".to_string(),
+        },
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "id": 1,
+            "firstName": "John",
+            "fullName": "John Smith",
+            "greet": "Hey, John!",
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Field \"fullName\" of \"Author\" is defined more than once")]
+fn test_complex_name_collision() {
+    #[allow(dead_code)]
+    #[derive(SimpleObject)]
+    #[graphql(complex)]
+    struct Author {
+        full_name: String,
+    }
+
+    #[ComplexObjectFields]
+    impl Author {
+        fn full_name(&self) -> String {
+            self.full_name.to_uppercase()
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        author: Author,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let _ = App::create_schema().finish();
+}
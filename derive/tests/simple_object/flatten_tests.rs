@@ -0,0 +1,106 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct Timestamps {
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(SimpleObject)]
+struct Audit {
+    #[graphql(flatten)]
+    timestamps: Timestamps,
+    author: String,
+}
+
+#[tokio::test]
+async fn test_flatten() {
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        id: i32,
+        #[graphql(flatten)]
+        audit: Audit,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Query {
+      id: Int!
+      createdAt: String!
+      updatedAt: String!
+      author: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            id
+            createdAt
+            updatedAt
+            author
+        }
+    "#;
+
+    let root = Query {
+        id: 1,
+        audit: Audit {
+            timestamps: Timestamps {
+                created_at: "2024-01-01".to_string(),
+                updated_at: "2024-01-02".to_string(),
+            },
+            author: "foo".to_string(),
+        },
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "id": 1,
+            "createdAt": "2024-01-01",
+            "updatedAt": "2024-01-02",
+            "author": "foo",
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Field \"author\" of \"Query\" is defined more than once")]
+fn test_flatten_name_collision() {
+    #[allow(dead_code)]
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        author: String,
+        #[graphql(flatten)]
+        audit: Audit,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let _ = App::create_schema().finish();
+}
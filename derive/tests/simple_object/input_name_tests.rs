@@ -0,0 +1,128 @@
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+/// A postal address
+#[derive(SimpleObject, Clone)]
+#[graphql(input_name = "AddressInput")]
+struct Address {
+    street: String,
+    /// two letter country code
+    country: String,
+    zip: Option<String>,
+    #[graphql(input_skip)]
+    verified: bool,
+    #[graphql(output_skip)]
+    note: Option<String>,
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn normalize(&self, address: Address) -> Address {
+        Address {
+            street: address.street.to_uppercase(),
+            verified: address.note.is_some(),
+            ..address
+        }
+    }
+}
+
+#[derive(App)]
+struct App(Query);
+
+#[tokio::test]
+async fn test_input_name() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    "A postal address"
+    type Address {
+      street: String!
+      "two letter country code"
+      country: String!
+      zip: String
+      verified: Boolean!
+    }
+
+    "A postal address"
+    input AddressInput {
+      street: String!
+      "two letter country code" country: String!
+      zip: String
+      note: String
+    }
+
+    type Query {
+      normalize(address: AddressInput!): Address!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            normalize(address: { street: "Main St", country: "US", note: "checked" }) {
+                street
+                country
+                zip
+                verified
+            }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "normalize": {
+                "street": "MAIN ST",
+                "country": "US",
+                "zip": null,
+                "verified": true,
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_input_name_invalid_field() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query($address: AddressInput!) {
+            normalize(address: $address) { street }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query)
+        .variables(dynamic_graphql::Variables::from_json(serde_json::json!({
+            "address": { "street": "Main St", "country": 1 }
+        })))
+        .root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r#"
+    Invalid value for argument "address.country", expected type "String"
+    "#);
+}
@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use dynamic_graphql::App;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[tokio::test]
+async fn test_map_as_entries() {
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        #[graphql(map_as = "entries")]
+        pub counts: BTreeMap<String, i32>,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type KeyValue_Int {
+      key: String!
+      value: Int!
+    }
+
+    type Query {
+      counts: [KeyValue_Int!]!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            counts {
+                key
+                value
+            }
+        }
+    "#;
+
+    let root = Query {
+        counts: BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)]),
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "counts": [
+                { "key": "a", "value": 1 },
+                { "key": "b", "value": 2 },
+            ]
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_map_as_entries_with_non_string_key() {
+    #[derive(SimpleObject, Clone)]
+    struct Foo {
+        pub value: String,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        #[graphql(map_as = "entries")]
+        pub foos: Option<BTreeMap<i32, Foo>>,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Foo {
+      value: String!
+    }
+
+    type KeyValue_Int_Foo {
+      key: Int!
+      value: Foo!
+    }
+
+    type Query {
+      foos: [KeyValue_Int_Foo!]
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            foos {
+                key
+                value { value }
+            }
+        }
+    "#;
+
+    let root = Query {
+        foos: Some(BTreeMap::from([(
+            1,
+            Foo {
+                value: "one".to_string(),
+            },
+        )])),
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({ "foos": [ { "key": 1, "value": { "value": "one" } } ] })
+    );
+
+    let root = Query { foos: None };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(data, serde_json::json!({ "foos": null }));
+}
+
+#[tokio::test]
+async fn test_map_as_json() {
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        #[graphql(map_as = "json")]
+        pub counts: HashMap<String, i32>,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    scalar JSON
+
+    type Query {
+      counts: JSON!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            counts
+        }
+    "#;
+
+    let root = Query {
+        counts: HashMap::from([("a".to_string(), 1)]),
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(data, serde_json::json!({ "counts": { "a": 1 } }));
+}
+
+#[tokio::test]
+async fn test_map_as_entries_with_list_and_optional_values() {
+    #[derive(SimpleObject)]
+    struct Foo {
+        pub value: String,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(root)]
+    struct Query {
+        #[graphql(map_as = "entries")]
+        pub tags: BTreeMap<String, Vec<String>>,
+        #[graphql(map_as = "entries")]
+        pub foos: BTreeMap<String, Option<Foo>>,
+    }
+
+    #[derive(App)]
+    struct App(Query);
+
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Foo {
+      value: String!
+    }
+
+    type KeyValue_ListOfString {
+      key: String!
+      value: [String!]!
+    }
+
+    type KeyValue_OptionalFoo {
+      key: String!
+      value: Foo
+    }
+
+    type Query {
+      tags: [KeyValue_ListOfString!]!
+      foos: [KeyValue_OptionalFoo!]!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            tags {
+                key
+                value
+            }
+            foos {
+                key
+                value { value }
+            }
+        }
+    "#;
+
+    let root = Query {
+        tags: BTreeMap::from([("a".to_string(), vec!["x".to_string(), "y".to_string()])]),
+        foos: BTreeMap::from([
+            (
+                "a".to_string(),
+                Some(Foo {
+                    value: "one".to_string(),
+                }),
+            ),
+            ("b".to_string(), None),
+        ]),
+    };
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "tags": [ { "key": "a", "value": ["x", "y"] } ],
+            "foos": [
+                { "key": "a", "value": { "value": "one" } },
+                { "key": "b", "value": null },
+            ]
+        })
+    );
+}
@@ -0,0 +1,135 @@
+use dynamic_graphql::App;
+use dynamic_graphql::Context;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+mod format {
+    use dynamic_graphql::Context;
+    use dynamic_graphql::Result;
+
+    pub fn cents_to_decimal(cents: &i64, _ctx: &Context) -> Result<f64> {
+        Ok(*cents as f64 / 100.0)
+    }
+}
+
+struct ShowEmail(bool);
+
+fn mask_email(email: &String, ctx: &Context) -> Result<String, String> {
+    if ctx.data_unchecked::<ShowEmail>().0 {
+        return Ok(email.clone());
+    }
+    match email.split_once('@') {
+        Some((_, domain)) => Ok(format!("***@{}", domain)),
+        None => Err(format!("Invalid email: {}", email)),
+    }
+}
+
+#[derive(SimpleObject)]
+struct Product {
+    #[graphql(resolve_with = "format::cents_to_decimal", ty = "f64")]
+    price: i64,
+    #[graphql(name = "contact", resolve_with = "mask_email", ty = "String")]
+    email: String,
+}
+
+#[derive(SimpleObject)]
+#[graphql(root)]
+struct Query {
+    product: Product,
+    #[graphql(flatten)]
+    featured: Product,
+}
+
+#[derive(App)]
+struct App(Query);
+
+fn root(email: &str) -> Query {
+    Query {
+        product: Product {
+            price: 1999,
+            email: email.to_string(),
+        },
+        featured: Product {
+            price: 500,
+            email: "sales@example.com".to_string(),
+        },
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_with() {
+    let schema = App::create_schema()
+        .data(ShowEmail(false))
+        .finish()
+        .unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Product {
+      price: Float!
+      contact: String!
+    }
+
+    type Query {
+      product: Product!
+      price: Float!
+      contact: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+
+    let query = r#"
+        query {
+            product { price contact }
+            price
+            contact
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query)
+        .root_value(FieldValue::owned_any(root("jane@example.com")));
+    let res = schema.execute(req).await;
+    let data = res.data.into_json().unwrap();
+
+    assert_eq!(
+        data,
+        serde_json::json!({
+            "product": { "price": 19.99, "contact": "***@example.com" },
+            "price": 5.0,
+            "contact": "***@example.com",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_with_error() {
+    let schema = App::create_schema()
+        .data(ShowEmail(false))
+        .finish()
+        .unwrap();
+
+    let query = r#"
+        query {
+            product { contact }
+        }
+    "#;
+
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(root("jane")));
+    let res = schema.execute(req).await;
+    assert_eq!(res.errors.len(), 1);
+    insta::assert_snapshot!(res.errors[0].message, @r"
+    Invalid email: jane
+    ");
+}
@@ -1,5 +1,6 @@
 mod schema_utils;
 mod union {
+    mod expand_union_tests;
     mod union_tests;
     mod with_generic_tests;
     mod with_interface;
@@ -0,0 +1,138 @@
+use dynamic_graphql::App;
+use dynamic_graphql::ExpandUnion;
+use dynamic_graphql::FieldValue;
+use dynamic_graphql::ResolvedObject;
+use dynamic_graphql::ResolvedObjectFields;
+use dynamic_graphql::SimpleObject;
+use dynamic_graphql::Union;
+use dynamic_graphql::UnionInstance;
+use dynamic_graphql::dynamic::DynamicRequestExt;
+
+use crate::schema_utils::normalize_schema;
+
+#[derive(SimpleObject)]
+struct Article {
+    title: String,
+}
+
+#[derive(Union)]
+enum SearchResult {
+    Article(Article),
+}
+
+// contributed by another module
+#[derive(SimpleObject)]
+struct Video {
+    url: String,
+}
+
+#[derive(SimpleObject)]
+struct Podcast {
+    episode: i32,
+}
+
+#[derive(ExpandUnion)]
+#[graphql(target(SearchResult))]
+enum MediaSearchResult {
+    Video(Video),
+    Podcast(Podcast),
+}
+
+#[derive(ResolvedObject)]
+#[graphql(root)]
+struct Query;
+
+#[ResolvedObjectFields]
+impl Query {
+    fn search(&self) -> Vec<UnionInstance<'static, SearchResult>> {
+        vec![
+            UnionInstance::new_owned(Article {
+                title: "Hello".to_string(),
+            }),
+            MediaSearchResult::Video(Video {
+                url: "https://example.com".to_string(),
+            })
+            .into(),
+            MediaSearchResult::Podcast(Podcast { episode: 7 }).into(),
+        ]
+    }
+
+    fn featured(&self) -> SearchResult {
+        SearchResult::Article(Article {
+            title: "Featured".to_string(),
+        })
+    }
+}
+
+#[derive(App)]
+struct App(Query, SearchResult, MediaSearchResult);
+
+#[test]
+fn test_schema() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let sdl = schema.sdl();
+    insta::assert_snapshot!(normalize_schema(&sdl), @r#"
+    type Article {
+      title: String!
+    }
+
+    type Podcast {
+      episode: Int!
+    }
+
+    type Query {
+      search: [SearchResult!]!
+      featured: SearchResult!
+    }
+
+    union SearchResult = Article | Video | Podcast
+
+    type Video {
+      url: String!
+    }
+
+    "Directs the executor to include this field or fragment only when the `if` argument is true."
+    directive @include(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    "Directs the executor to skip this field or fragment when the `if` argument is true."
+    directive @skip(if: Boolean!) on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+    schema {
+      query: Query
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn test_query() {
+    let schema = App::create_schema().finish().unwrap();
+
+    let query = r#"
+        query {
+            search {
+                __typename
+                ... on Article { title }
+                ... on Video { url }
+                ... on Podcast { episode }
+            }
+            featured {
+                ... on Article { title }
+            }
+        }
+    "#;
+    let req = dynamic_graphql::Request::new(query).root_value(FieldValue::owned_any(Query));
+    let res = schema.execute(req).await;
+    assert!(res.errors.is_empty(), "{:?}", res.errors);
+    assert_eq!(
+        res.data.into_json().unwrap(),
+        serde_json::json!({
+            "search": [
+                { "__typename": "Article", "title": "Hello" },
+                { "__typename": "Video", "url": "https://example.com" },
+                { "__typename": "Podcast", "episode": 7 },
+            ],
+            "featured": { "title": "Featured" },
+        })
+    );
+}
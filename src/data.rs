@@ -2,6 +2,7 @@ use std::any::Any;
 use std::any::TypeId;
 
 use async_graphql::Context;
+use async_graphql::Error;
 use fnv::FnvHashMap;
 
 pub struct SchemaData(FnvHashMap<TypeId, Box<dyn Any + Sync + Send>>);
@@ -57,3 +58,17 @@ impl GetSchemaData for Context<'_> {
         self.data_unchecked()
     }
 }
+
+/// Get `T` from the request data, falling back to the schema data
+///
+/// Used by `#[graphql(data)]` arguments.
+pub fn get_context_data<'a, T: Any + Sync + Send>(ctx: &Context<'a>) -> Result<&'a T, Error> {
+    ctx.data_opt::<T>()
+        .or_else(|| ctx.data_opt::<SchemaData>()?.get::<T>())
+        .ok_or_else(|| {
+            Error::new(format!(
+                "Data `{}` does not exist.",
+                std::any::type_name::<T>()
+            ))
+        })
+}
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use crate::Context;
+use crate::Error;
+use crate::FieldValue;
+use crate::Name;
+use crate::Result;
+use crate::Value;
+use crate::data::SchemaData;
+use crate::dynamic;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::types::InputTypeName;
+use crate::types::OutputTypeName;
+use crate::types::Register;
+use crate::types::TypeName;
+
+/// A value of a [`DynamicEnum`]
+#[derive(Debug, Clone)]
+pub struct DynamicEnumItem {
+    name: String,
+    description: Option<String>,
+    // `Some(reason)` when deprecated
+    deprecation: Option<Option<String>>,
+}
+
+impl DynamicEnumItem {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            deprecation: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn deprecation(mut self, reason: Option<&str>) -> Self {
+        self.deprecation = Some(reason.map(str::to_string));
+        self
+    }
+}
+
+impl<T: Into<String>> From<T> for DynamicEnumItem {
+    fn from(name: T) -> Self {
+        Self::new(name)
+    }
+}
+
+/// An enum defined at runtime, e.g. from configuration, registered with
+/// [`Registry::register_dynamic_enum`]
+#[derive(Debug, Clone)]
+pub struct DynamicEnum {
+    name: String,
+    description: Option<String>,
+    items: Vec<DynamicEnumItem>,
+}
+
+impl DynamicEnum {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn item(mut self, item: impl Into<DynamicEnumItem>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = impl Into<DynamicEnumItem>>) -> Self {
+        self.items.extend(items.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Check that the enum and its values have legal GraphQL names
+    pub fn validate(&self) -> Result<()> {
+        if !is_valid_name(&self.name) {
+            return Err(Error::new(format!(
+                "Enum name `{}` is not a valid GraphQL name.",
+                self.name
+            )));
+        }
+        if self.items.is_empty() {
+            return Err(Error::new(format!(
+                "Enum `{}` must define at least one value.",
+                self.name
+            )));
+        }
+        let mut names = HashSet::new();
+        for item in &self.items {
+            if !is_valid_name(&item.name) || matches!(item.name.as_str(), "true" | "false" | "null")
+            {
+                return Err(Error::new(format!(
+                    "Value `{}` of enum `{}` is not a valid GraphQL enum value.",
+                    item.name, self.name
+                )));
+            }
+            if !names.insert(item.name.as_str()) {
+                return Err(Error::new(format!(
+                    "Value `{}` of enum `{}` is defined more than once.",
+                    item.name, self.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn into_enum(self) -> dynamic::Enum {
+        let object = dynamic::Enum::new(self.name);
+        let object = match self.description {
+            Some(description) => object.description(description),
+            None => object,
+        };
+        self.items.into_iter().fold(object, |object, item| {
+            let field = dynamic::EnumItem::new(item.name);
+            let field = match item.description {
+                Some(description) => field.description(description),
+                None => field,
+            };
+            let field = match item.deprecation {
+                Some(reason) => field.deprecation(reason.as_deref()),
+                None => field,
+            };
+            object.item(field)
+        })
+    }
+}
+
+/// names must match `/[_A-Za-z][_0-9A-Za-z]*/` and the `__` prefix is reserved
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let valid_start = chars
+        .next()
+        .is_some_and(|c| c == '_' || c.is_ascii_alphabetic());
+    valid_start && chars.all(|c| c == '_' || c.is_ascii_alphanumeric()) && !name.starts_with("__")
+}
+
+/// The values of each registered dynamic enum, stored in the schema data
+#[derive(Default)]
+struct DynamicEnums(HashMap<String, Vec<String>>);
+
+impl Registry {
+    /// Validate and register an enum defined at runtime
+    pub fn register_dynamic_enum(mut self, dynamic_enum: DynamicEnum) -> Result<Self> {
+        dynamic_enum.validate()?;
+        let values = dynamic_enum
+            .items
+            .iter()
+            .map(|item| item.name.clone())
+            .collect();
+        self.data
+            .get_mut_or_default::<DynamicEnums>()
+            .0
+            .insert(dynamic_enum.name.clone(), values);
+        Ok(self.register_type(dynamic_enum.into_enum()))
+    }
+}
+
+/// A value of the dynamic enum named by `N`, usable as an argument and as an output
+///
+/// `N` is any type implementing `TypeName` with the name of a registered [`DynamicEnum`], its
+/// `Register` implementation can be left empty.
+pub struct DynamicEnumValue<N> {
+    value: String,
+    _name: PhantomData<fn() -> N>,
+}
+
+impl<N> DynamicEnumValue<N> {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            _name: PhantomData,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    pub fn into_string(self) -> String {
+        self.value
+    }
+}
+
+impl<N: TypeName> DynamicEnumValue<N> {
+    /// The position of the value in the registered enum
+    pub fn index(&self, ctx: &Context) -> Option<usize> {
+        ctx.data_opt::<SchemaData>()?
+            .get::<DynamicEnums>()?
+            .0
+            .get(N::get_type_name().as_ref())?
+            .iter()
+            .position(|value| *value == self.value)
+    }
+}
+
+impl<N> Clone for DynamicEnumValue<N> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<N> Debug for DynamicEnumValue<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DynamicEnumValue")
+            .field(&self.value)
+            .finish()
+    }
+}
+
+impl<N> PartialEq for DynamicEnumValue<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<N> Eq for DynamicEnumValue<N> {}
+
+impl<N: TypeName> TypeName for DynamicEnumValue<N> {
+    fn get_type_name() -> std::borrow::Cow<'static, str> {
+        N::get_type_name()
+    }
+}
+
+impl<N: TypeName> InputTypeName for DynamicEnumValue<N> {}
+
+impl<N: TypeName> OutputTypeName for DynamicEnumValue<N> {}
+
+// the enum itself is registered at runtime with `Registry::register_dynamic_enum`
+impl<N> Register for DynamicEnumValue<N> {}
+
+impl<N: TypeName> FromValue for DynamicEnumValue<N> {
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        Ok(Self::new(value?.enum_name()?))
+    }
+}
+
+impl<'a, N> ResolveOwned<'a> for DynamicEnumValue<N> {
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(Value::Enum(Name::new(self.value)))))
+    }
+}
+
+impl<'a, N> ResolveRef<'a> for DynamicEnumValue<N> {
+    fn resolve_ref(&'a self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(Value::Enum(Name::new(&self.value)))))
+    }
+}
@@ -0,0 +1,152 @@
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::Context;
+use crate::Error;
+use crate::Result;
+use crate::data::SchemaData;
+use crate::dynamic;
+use crate::errors::InputValueError;
+use crate::errors::InputValueResult;
+use crate::flatten::FlattenInputObject;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::type_ref_builder::TypeRefBuilder;
+use crate::types::ExpandInputObject;
+use crate::types::GetInputTypeRef;
+use crate::types::Register;
+
+type ParseExpansionFn = fn(&dynamic::ObjectAccessor) -> Result<Box<dyn Any + Send + Sync>>;
+
+struct InputExpansion {
+    expansion: TypeId,
+    parse: ParseExpansionFn,
+}
+
+/// The expansions of each input object in a schema, keyed by the input object type
+///
+/// Kept in the schema data, `ExpandedInput` reads it through the context when an argument is parsed.
+#[derive(Default)]
+pub struct InputExpansions(HashMap<TypeId, Vec<InputExpansion>>);
+
+impl InputExpansions {
+    /// Parse the fields of the expansion `E` whenever an `ExpandedInput<E::Target>` is parsed
+    pub fn add<E>(&mut self)
+    where
+        E: ExpandInputObject + FlattenInputObject + Send + Sync + 'static,
+    {
+        let expansions = self.0.entry(TypeId::of::<E::Target>()).or_default();
+        if expansions
+            .iter()
+            .any(|expansion| expansion.expansion == TypeId::of::<E>())
+        {
+            return;
+        }
+        expansions.push(InputExpansion {
+            expansion: TypeId::of::<E>(),
+            parse: |object| Ok(Box::new(E::from_flatten_object(object)?)),
+        });
+    }
+}
+
+/// An input object together with the fields added to it by `#[derive(ExpandInputObject)]`
+///
+/// The expansions are parsed with the argument, from the ones registered in the current schema.
+/// An `ExpandedInput` nested in another input object has no access to the schema, so it has no
+/// expansions.
+pub struct ExpandedInput<T> {
+    value: T,
+    expansions: Vec<(TypeId, Result<Box<dyn Any + Send + Sync>>)>,
+}
+
+impl<T> ExpandedInput<T> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// The fields added by the expansion `E`
+    pub fn expansion<E>(&self) -> Result<&E>
+    where
+        E: ExpandInputObject<Target = T> + 'static,
+    {
+        let (_, expansion) = self
+            .expansions
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<E>())
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "Input expansion `{}` is not registered.",
+                    std::any::type_name::<E>()
+                ))
+            })?;
+        match expansion {
+            Ok(expansion) => Ok(expansion
+                .downcast_ref::<E>()
+                .expect("input expansion of another type")),
+            Err(err) => Err(err.clone()),
+        }
+    }
+}
+
+impl<T> Deref for ExpandedInput<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> FromValue for ExpandedInput<T>
+where
+    T: FromValue + GetInputTypeRef + 'static,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        Ok(Self {
+            value: T::from_value(value).map_err(InputValueError::propagate)?,
+            expansions: Vec::new(),
+        })
+    }
+
+    fn from_arg_value(
+        ctx: &Context,
+        value: Result<dynamic::ValueAccessor>,
+    ) -> InputValueResult<Self> {
+        let value = value?;
+        let object = value.object();
+        let inner = T::from_value(Ok(value)).map_err(InputValueError::propagate)?;
+        let object = object?;
+        let expansions = ctx
+            .data_opt::<SchemaData>()
+            .and_then(|data| data.get::<InputExpansions>())
+            .and_then(|input_expansions| input_expansions.0.get(&TypeId::of::<T>()))
+            .map(|expansions| {
+                expansions
+                    .iter()
+                    .map(|expansion| (expansion.expansion, (expansion.parse)(&object)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            value: inner,
+            expansions,
+        })
+    }
+}
+
+impl<T: GetInputTypeRef> GetInputTypeRef for ExpandedInput<T> {
+    #[inline]
+    fn get_input_type_ref() -> TypeRefBuilder {
+        T::get_input_type_ref()
+    }
+}
+
+impl<T> Register for ExpandedInput<T>
+where
+    T: Register + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        registry.register::<T>()
+    }
+}
@@ -0,0 +1,373 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use crate::ID;
+use crate::Result;
+use crate::Value;
+use crate::dynamic;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::types::GetInputTypeRef;
+use crate::types::InputObject;
+use crate::types::InputTypeName;
+use crate::types::Register;
+use crate::types::TypeName;
+
+/// A comparison operator of a [`FilterPredicate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    Eq,
+    Ne,
+    In,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+impl FilterOp {
+    /// The name of the operator field in the filter input
+    pub fn name(&self) -> &'static str {
+        match self {
+            FilterOp::Eq => "eq",
+            FilterOp::Ne => "ne",
+            FilterOp::In => "in",
+            FilterOp::Lt => "lt",
+            FilterOp::Lte => "lte",
+            FilterOp::Gt => "gt",
+            FilterOp::Gte => "gte",
+            FilterOp::Contains => "contains",
+            FilterOp::StartsWith => "startsWith",
+            FilterOp::EndsWith => "endsWith",
+        }
+    }
+}
+
+/// A parsed filter, independent of the Rust type it was generated for.
+///
+/// Field paths are the Rust field names joined with `.` for nested filters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterPredicate {
+    /// Matches when all predicates match, an empty list always matches
+    And(Vec<FilterPredicate>),
+    /// Matches when any predicate matches
+    Or(Vec<FilterPredicate>),
+    Not(Box<FilterPredicate>),
+    Compare {
+        field: String,
+        op: FilterOp,
+        value: Value,
+    },
+}
+
+impl FilterPredicate {
+    /// Combine predicates with `And`, unwrapping a single predicate
+    pub fn and(mut predicates: Vec<FilterPredicate>) -> Self {
+        if predicates.len() == 1 {
+            predicates.remove(0)
+        } else {
+            FilterPredicate::And(predicates)
+        }
+    }
+}
+
+/// A parsed filter input that can be turned into a [`FilterPredicate`]
+pub trait Filter {
+    fn to_predicate(&self, field: &str) -> FilterPredicate;
+}
+
+/// A filter that can be evaluated against values of `T`
+///
+/// `value` is `None` when the value is missing, e.g. an optional field set to `None`.
+/// A missing value only matches a filter without any operators.
+pub trait FieldFilter<T: ?Sized>: Filter {
+    fn matches(&self, value: Option<&T>) -> bool;
+}
+
+impl<T, F: FieldFilter<T>> FieldFilter<Option<T>> for F {
+    fn matches(&self, value: Option<&Option<T>>) -> bool {
+        FieldFilter::<T>::matches(self, value.and_then(Option::as_ref))
+    }
+}
+
+/// A type whose values can be filtered with `#[derive(FilterInput)]`
+pub trait Filterable {
+    type Filter: FieldFilter<Self> + InputObject + FromValue + Debug + Clone + Default + 'static;
+}
+
+impl<T: Filterable> Filterable for Option<T> {
+    type Filter = T::Filter;
+}
+
+/// Join a parent field path with a field name
+pub fn get_filter_field_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", parent, name)
+    }
+}
+
+macro_rules! scalar_filter {
+    ($(#[$meta:meta])* $name:ident { $($field:ident: $ty:ty => $op:ident),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq)]
+        pub struct $name {
+            $(pub $field: Option<$ty>,)*
+        }
+
+        impl $name {
+            /// Whether no operator is set
+            pub fn is_empty(&self) -> bool {
+                true $(&& self.$field.is_none())*
+            }
+        }
+
+        impl TypeName for $name {
+            fn get_type_name() -> Cow<'static, str> {
+                stringify!($name).into()
+            }
+        }
+
+        impl InputTypeName for $name {}
+
+        impl InputObject for $name {}
+
+        impl Register for $name {
+            fn register(registry: Registry) -> Registry {
+                let object = dynamic::InputObject::new(
+                    <Self as InputObject>::get_input_object_type_name().as_ref(),
+                );
+                $(
+                    let object = object.field(dynamic::InputValue::new(
+                        FilterOp::$op.name(),
+                        <Option<$ty> as GetInputTypeRef>::get_input_type_ref(),
+                    ));
+                )*
+                registry.register_type(object)
+            }
+        }
+
+        impl FromValue for $name {
+            fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+                let value = value?;
+                let object = value.object()?;
+                Ok(Self {
+                    $(
+                        $field: FromValue::from_value(object.try_get(FilterOp::$op.name()))
+                            .map_err(|e| e.into_field_error(FilterOp::$op.name()))?,
+                    )*
+                })
+            }
+        }
+
+        impl Filter for $name {
+            fn to_predicate(&self, field: &str) -> FilterPredicate {
+                let mut predicates = Vec::new();
+                $(
+                    if let Some(value) = &self.$field {
+                        predicates.push(FilterPredicate::Compare {
+                            field: field.to_string(),
+                            op: FilterOp::$op,
+                            value: value.to_value(),
+                        });
+                    }
+                )*
+                FilterPredicate::and(predicates)
+            }
+        }
+    };
+}
+
+trait FilterValue {
+    fn to_value(&self) -> Value;
+}
+
+macro_rules! filter_values {
+    ($($ty:ty),*) => {
+        $(
+            impl FilterValue for $ty {
+                fn to_value(&self) -> Value {
+                    Value::from(self.clone())
+                }
+            }
+        )*
+    };
+}
+
+filter_values!(String, i64, f64, bool);
+
+impl FilterValue for ID {
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl<T: FilterValue> FilterValue for Vec<T> {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(FilterValue::to_value).collect())
+    }
+}
+
+fn check<T>(operand: &Option<T>, f: impl FnOnce(&T) -> bool) -> bool {
+    operand.as_ref().is_none_or(f)
+}
+
+scalar_filter! {
+    /// Operators available on `String` fields
+    StringFilter {
+        eq: String => Eq,
+        ne: String => Ne,
+        r#in: Vec<String> => In,
+        contains: String => Contains,
+        starts_with: String => StartsWith,
+        ends_with: String => EndsWith,
+    }
+}
+
+scalar_filter! {
+    /// Operators available on `Int` fields
+    IntFilter {
+        eq: i64 => Eq,
+        ne: i64 => Ne,
+        r#in: Vec<i64> => In,
+        lt: i64 => Lt,
+        lte: i64 => Lte,
+        gt: i64 => Gt,
+        gte: i64 => Gte,
+    }
+}
+
+scalar_filter! {
+    /// Operators available on `Float` fields
+    FloatFilter {
+        eq: f64 => Eq,
+        ne: f64 => Ne,
+        lt: f64 => Lt,
+        lte: f64 => Lte,
+        gt: f64 => Gt,
+        gte: f64 => Gte,
+    }
+}
+
+scalar_filter! {
+    /// Operators available on `Boolean` fields
+    BooleanFilter {
+        eq: bool => Eq,
+        ne: bool => Ne,
+    }
+}
+
+scalar_filter! {
+    /// Operators available on `ID` fields
+    IDFilter {
+        eq: ID => Eq,
+        ne: ID => Ne,
+        r#in: Vec<ID> => In,
+    }
+}
+
+impl FieldFilter<String> for StringFilter {
+    fn matches(&self, value: Option<&String>) -> bool {
+        let Some(value) = value else {
+            return self.is_empty();
+        };
+        check(&self.eq, |eq| value == eq)
+            && check(&self.ne, |ne| value != ne)
+            && check(&self.r#in, |list| list.contains(value))
+            && check(&self.contains, |s| value.contains(s.as_str()))
+            && check(&self.starts_with, |s| value.starts_with(s.as_str()))
+            && check(&self.ends_with, |s| value.ends_with(s.as_str()))
+    }
+}
+
+impl Filterable for String {
+    type Filter = StringFilter;
+}
+
+macro_rules! int_filterable {
+    ($($ty:ty),*) => {
+        $(
+            impl FieldFilter<$ty> for IntFilter {
+                fn matches(&self, value: Option<&$ty>) -> bool {
+                    let Some(value) = value else {
+                        return self.is_empty();
+                    };
+                    let value = *value as i128;
+                    check(&self.eq, |eq| value == i128::from(*eq))
+                        && check(&self.ne, |ne| value != i128::from(*ne))
+                        && check(&self.r#in, |list| list.iter().any(|item| value == i128::from(*item)))
+                        && check(&self.lt, |lt| value < i128::from(*lt))
+                        && check(&self.lte, |lte| value <= i128::from(*lte))
+                        && check(&self.gt, |gt| value > i128::from(*gt))
+                        && check(&self.gte, |gte| value >= i128::from(*gte))
+                }
+            }
+
+            impl Filterable for $ty {
+                type Filter = IntFilter;
+            }
+        )*
+    };
+}
+
+int_filterable!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! float_filterable {
+    ($($ty:ty),*) => {
+        $(
+            impl FieldFilter<$ty> for FloatFilter {
+                fn matches(&self, value: Option<&$ty>) -> bool {
+                    let Some(value) = value else {
+                        return self.is_empty();
+                    };
+                    let value = f64::from(*value);
+                    check(&self.eq, |eq| value == *eq)
+                        && check(&self.ne, |ne| value != *ne)
+                        && check(&self.lt, |lt| value < *lt)
+                        && check(&self.lte, |lte| value <= *lte)
+                        && check(&self.gt, |gt| value > *gt)
+                        && check(&self.gte, |gte| value >= *gte)
+                }
+            }
+
+            impl Filterable for $ty {
+                type Filter = FloatFilter;
+            }
+        )*
+    };
+}
+
+float_filterable!(f32, f64);
+
+impl FieldFilter<bool> for BooleanFilter {
+    fn matches(&self, value: Option<&bool>) -> bool {
+        let Some(value) = value else {
+            return self.is_empty();
+        };
+        check(&self.eq, |eq| value == eq) && check(&self.ne, |ne| value != ne)
+    }
+}
+
+impl Filterable for bool {
+    type Filter = BooleanFilter;
+}
+
+impl FieldFilter<ID> for IDFilter {
+    fn matches(&self, value: Option<&ID>) -> bool {
+        let Some(value) = value else {
+            return self.is_empty();
+        };
+        check(&self.eq, |eq| value == eq)
+            && check(&self.ne, |ne| value != ne)
+            && check(&self.r#in, |list| list.contains(value))
+    }
+}
+
+impl Filterable for ID {
+    type Filter = IDFilter;
+}
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+use crate::Result;
+use crate::dynamic;
+use crate::registry::Registry;
+
+/// A path from the value of a parent object to a flattened field of type `T`
+pub trait FlattenPath<T> {
+    /// The type stored in the parent `FieldValue`
+    type Parent: 'static;
+    fn project(parent: &Self::Parent) -> &T;
+}
+
+/// The path from a value to itself, used when the object is not flattened
+pub struct FlattenRoot<T>(PhantomData<T>);
+
+impl<T: 'static> FlattenPath<T> for FlattenRoot<T> {
+    type Parent = T;
+    #[inline]
+    fn project(parent: &Self::Parent) -> &T {
+        parent
+    }
+}
+
+/// Follows the path `A` to `B`, then the flattened field of `B`
+pub struct FlattenThen<A, B>(PhantomData<(A, B)>);
+
+impl<A, B, T> FlattenPath<T> for FlattenThen<A, B>
+where
+    A: FlattenPath<B>,
+    B: FlattenPath<T, Parent = B> + 'static,
+{
+    type Parent = A::Parent;
+    #[inline]
+    fn project(parent: &Self::Parent) -> &T {
+        B::project(A::project(parent))
+    }
+}
+
+/// An object whose fields can be hoisted into another object with `#[graphql(flatten)]`
+pub trait FlattenObject: Sized {
+    /// names of the fields added to the parent object
+    fn get_flatten_field_names() -> Vec<&'static str>;
+    fn register_flatten_types(registry: Registry) -> Registry;
+    fn flatten_fields<P: FlattenPath<Self>>(object: dynamic::Object) -> dynamic::Object;
+}
+
+/// An input object whose fields can be hoisted into another input object with `#[graphql(flatten)]`
+pub trait FlattenInputObject: Sized {
+    /// names of the fields added to the parent input object
+    fn get_flatten_field_names() -> Vec<&'static str>;
+    fn register_flatten_types(registry: Registry) -> Registry;
+    fn flatten_fields(object: dynamic::InputObject) -> dynamic::InputObject;
+    fn from_flatten_object(object: &dynamic::ObjectAccessor) -> Result<Self>;
+}
+
+/// A set of arguments that can be spread into a resolver with `#[graphql(flatten)]`
+pub trait FlattenArgs: Sized {
+    /// names of the arguments added to the field
+    fn get_flatten_arg_names() -> Vec<&'static str>;
+    fn register_args_types(registry: Registry) -> Registry;
+    fn define_args(field: dynamic::Field) -> dynamic::Field;
+    fn from_args(args: &dynamic::ObjectAccessor) -> Result<Self>;
+}
+
+/// Panics if a field name is used more than once, e.g. by two flattened fields
+/// or by a struct field and a complex method
+pub fn check_flatten_field_names(type_name: &str, names: Vec<&'static str>) {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            panic!(
+                "Field \"{}\" of \"{}\" is defined more than once, check the flattened and complex fields",
+                name, type_name
+            );
+        }
+    }
+}
+
+/// Panics if an argument name is used more than once, e.g. by two flattened arguments
+pub fn check_flatten_arg_names(field_name: &str, names: Vec<&'static str>) {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            panic!(
+                "Argument \"{}\" of field \"{}\" is defined more than once, check the flattened arguments",
+                name, field_name
+            );
+        }
+    }
+}
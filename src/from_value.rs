@@ -1,3 +1,4 @@
+use crate::Context;
 use crate::MaybeUndefined;
 use crate::Result;
 use crate::dynamic;
@@ -7,6 +8,14 @@ use crate::types::GetInputTypeRef;
 
 pub trait FromValue: Sized {
     fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self>;
+
+    /// Parse the value of a field argument, with access to the context
+    fn from_arg_value(
+        _ctx: &Context,
+        value: Result<dynamic::ValueAccessor>,
+    ) -> InputValueResult<Self> {
+        Self::from_value(value)
+    }
 }
 
 impl FromValue for String {
@@ -92,6 +101,19 @@ where
             )),
         }
     }
+
+    fn from_arg_value(
+        ctx: &Context,
+        value: Result<dynamic::ValueAccessor>,
+    ) -> InputValueResult<Self> {
+        match value.ok() {
+            None => Ok(None),
+            Some(value) if value.is_null() => Ok(None),
+            Some(value) => Ok(Some(
+                T::from_arg_value(ctx, Ok(value)).map_err(InputValueError::propagate)?,
+            )),
+        }
+    }
 }
 
 impl<T> FromValue for Result<T>
@@ -130,4 +152,15 @@ where
             .map(|v| T::from_value(Ok(v)).map_err(InputValueError::propagate))
             .collect()
     }
+
+    fn from_arg_value(
+        ctx: &Context,
+        value: Result<dynamic::ValueAccessor>,
+    ) -> InputValueResult<Self> {
+        value?
+            .list()?
+            .iter()
+            .map(|v| T::from_arg_value(ctx, Ok(v)).map_err(InputValueError::propagate))
+            .collect()
+    }
 }
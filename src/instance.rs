@@ -12,6 +12,8 @@ use crate::types::Object;
 use crate::types::OutputTypeName;
 use crate::types::Register;
 use crate::types::TypeName;
+use crate::types::Union;
+use crate::types::UnionMark;
 
 pub struct Instance<'v, I, T = ()>
 where
@@ -39,7 +41,7 @@ where
         }
     }
     #[inline]
-    pub fn new_borrowed<T>(value: &T) -> Instance<I>
+    pub fn new_borrowed<T>(value: &T) -> Instance<'_, I>
     where
         T: InterfaceMark<I> + Object + Send + Sync + 'static,
     {
@@ -108,3 +110,71 @@ where
 }
 
 impl<I> OutputTypeName for Instance<'_, I> where I: Interface + 'static + ?Sized {}
+
+/// A value of the union `U`, holding any object marked as one of its possible types,
+/// including the ones added with `#[derive(ExpandUnion)]`
+pub struct UnionInstance<'v, U>
+where
+    U: ?Sized,
+    U: Union,
+{
+    _union: std::marker::PhantomData<U>,
+    value: AnyBox<'v>,
+}
+
+impl<U: ?Sized> UnionInstance<'_, U>
+where
+    U: Union,
+{
+    #[inline]
+    pub fn new_owned<'a, T>(value: T) -> UnionInstance<'a, U>
+    where
+        T: UnionMark<U> + Object + Send + Sync + 'static,
+    {
+        UnionInstance {
+            _union: std::marker::PhantomData,
+            value: AnyBox::new_owned(value, <T as Object>::get_object_type_name().to_string()),
+        }
+    }
+    #[inline]
+    pub fn new_borrowed<T>(value: &T) -> UnionInstance<'_, U>
+    where
+        T: UnionMark<U> + Object + Send + Sync + 'static,
+    {
+        UnionInstance {
+            _union: std::marker::PhantomData,
+            value: AnyBox::new_borrowed(value, <T as Object>::get_object_type_name().to_string()),
+        }
+    }
+}
+
+impl<'a, U> ResolveOwned<'a> for UnionInstance<'a, U>
+where
+    U: ?Sized + Union,
+{
+    fn resolve_owned(self, ctx: &Context) -> async_graphql::Result<Option<FieldValue<'a>>> {
+        self.value.resolve_owned(ctx)
+    }
+}
+
+impl<U> Register for UnionInstance<'_, U>
+where
+    U: ?Sized,
+    U: Union + Register + 'static,
+{
+    #[inline]
+    fn register(registry: Registry) -> Registry {
+        registry.register::<U>()
+    }
+}
+
+impl<U> TypeName for UnionInstance<'_, U>
+where
+    U: Union + 'static + ?Sized,
+{
+    fn get_type_name() -> Cow<'static, str> {
+        <U as Union>::get_union_type_name()
+    }
+}
+
+impl<U> OutputTypeName for UnionInstance<'_, U> where U: Union + 'static + ?Sized {}
@@ -1,10 +1,29 @@
 mod any_box;
 mod data;
+mod dynamic_enum;
 mod errors;
+mod expand_input;
+mod filter;
+mod flatten;
 mod from_value;
 mod instance;
+mod map;
+mod order_by;
 mod registry;
 mod resolve;
+mod result_union;
+#[cfg(any(
+    feature = "chrono",
+    feature = "time",
+    feature = "uuid",
+    feature = "url",
+    feature = "rust_decimal",
+    feature = "bigdecimal"
+))]
+mod scalars;
+mod selection;
+#[cfg(feature = "serde")]
+mod serde_scalar;
 mod type_ref_builder;
 mod types;
 mod upload;
@@ -44,17 +63,51 @@ pub use async_graphql::value;
 
 pub mod internal {
     pub use crate::any_box::AnyBox;
+    pub use crate::data::get_context_data;
     pub use crate::errors::InputValueError;
     pub use crate::errors::InputValueResult;
+    pub use crate::expand_input::InputExpansions;
+    pub use crate::filter::get_filter_field_path;
+    pub use crate::flatten::FlattenArgs;
+    pub use crate::flatten::FlattenInputObject;
+    pub use crate::flatten::FlattenObject;
+    pub use crate::flatten::FlattenPath;
+    pub use crate::flatten::FlattenRoot;
+    pub use crate::flatten::FlattenThen;
+    pub use crate::flatten::check_flatten_arg_names;
+    pub use crate::flatten::check_flatten_field_names;
     pub use crate::from_value::FromValue;
     pub use crate::instance::RegisterInstance;
+    pub use crate::map::AsEntries;
+    pub use crate::map::AsJson;
+    pub use crate::map::IntoMapOutput;
+    pub use crate::map::JsonMap;
+    pub use crate::map::MapEntries;
+    pub use crate::map::MapEntry;
+    pub use crate::map::MapJson;
+    pub use crate::map::MapRepr;
+    pub use crate::map::OutputMap;
+    pub use crate::order_by::compare_order_values;
+    pub use crate::registry::InterfaceCast;
     pub use crate::registry::Registry;
     pub use crate::resolve::Resolve;
     pub use crate::resolve::ResolveOwned;
     pub use crate::resolve::ResolveRef;
+    pub use crate::result_union::ResultUnion;
+    pub use crate::result_union::UnionMembers;
+    pub use crate::result_union::register_result_union;
+    pub use crate::selection::SelectableFields;
+    #[cfg(feature = "serde")]
+    pub use crate::serde_scalar::from_scalar_value;
+    #[cfg(feature = "serde")]
+    pub use crate::serde_scalar::to_scalar_value;
     pub use crate::type_ref_builder::TypeRefBuilder;
+    pub use crate::types::ComplexObject;
     pub use crate::types::Enum;
+    pub use crate::types::ExpandInputObject;
+    pub use crate::types::ExpandInterface;
     pub use crate::types::ExpandObject;
+    pub use crate::types::ExpandUnion;
     pub use crate::types::GetInputTypeRef;
     pub use crate::types::GetOutputTypeRef;
     pub use crate::types::InputObject;
@@ -70,16 +123,29 @@ pub mod internal {
     pub use crate::types::Scalar;
     pub use crate::types::TypeName;
     pub use crate::types::Union;
+    pub use crate::types::UnionMark;
 }
 
 pub mod experimental {
     pub use crate::data::GetSchemaData;
 }
 
+pub use dynamic_enum::DynamicEnum;
+pub use dynamic_enum::DynamicEnumItem;
+pub use dynamic_enum::DynamicEnumValue;
 pub use dynamic_graphql_derive::App;
+pub use dynamic_graphql_derive::Args;
+pub use dynamic_graphql_derive::ComplexObjectFields;
+#[doc = include_str!("./docs/enum.md")]
 pub use dynamic_graphql_derive::Enum;
+pub use dynamic_graphql_derive::ExpandInputObject;
+pub use dynamic_graphql_derive::ExpandInterface;
+pub use dynamic_graphql_derive::ExpandInterfaceFields;
 pub use dynamic_graphql_derive::ExpandObject;
 pub use dynamic_graphql_derive::ExpandObjectFields;
+pub use dynamic_graphql_derive::ExpandUnion;
+pub use dynamic_graphql_derive::FilterInput;
+pub use dynamic_graphql_derive::GraphQLError;
 pub use dynamic_graphql_derive::InputObject;
 #[doc = include_str!("./docs/interface.md")]
 pub use dynamic_graphql_derive::Interface;
@@ -87,6 +153,8 @@ pub use dynamic_graphql_derive::Mutation;
 pub use dynamic_graphql_derive::MutationFields;
 pub use dynamic_graphql_derive::MutationRoot;
 pub use dynamic_graphql_derive::OneOfInput;
+pub use dynamic_graphql_derive::OrderByInput;
+pub use dynamic_graphql_derive::PatchInput;
 #[doc = include_str!("./docs/resolved-object.md")]
 pub use dynamic_graphql_derive::ResolvedObject;
 #[doc = include_str!("./docs/resolved-object-fields.md")]
@@ -95,5 +163,23 @@ pub use dynamic_graphql_derive::Scalar;
 #[doc = include_str!("./docs/simple-object.md")]
 pub use dynamic_graphql_derive::SimpleObject;
 pub use dynamic_graphql_derive::Union;
+pub use expand_input::ExpandedInput;
+pub use filter::BooleanFilter;
+pub use filter::FieldFilter;
+pub use filter::Filter;
+pub use filter::FilterOp;
+pub use filter::FilterPredicate;
+pub use filter::Filterable;
+pub use filter::FloatFilter;
+pub use filter::IDFilter;
+pub use filter::IntFilter;
+pub use filter::StringFilter;
 pub use instance::Instance;
+pub use instance::UnionInstance;
+pub use order_by::OrderBy;
+pub use order_by::SortDirection;
+pub use resolve::IntOutputPolicy;
+pub use selection::SelectedFields;
+pub use types::BigInt;
+pub use types::List;
 pub use types::ScalarValue;
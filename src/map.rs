@@ -0,0 +1,328 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use async_graphql::indexmap::IndexMap;
+
+use crate::Context;
+use crate::Error;
+use crate::FieldValue;
+use crate::Name;
+use crate::Result;
+use crate::Value;
+use crate::dynamic;
+use crate::registry::Registry;
+use crate::resolve::Resolve;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::type_ref_builder::TypeRefBuilder;
+use crate::types::GetOutputTypeRef;
+use crate::types::Object;
+use crate::types::OutputTypeName;
+use crate::types::ParentType;
+use crate::types::Register;
+use crate::types::TypeName;
+
+const JSON: &str = "JSON";
+
+/// A map that can be turned into a list of `{ key, value }` entries for output
+pub trait OutputMap {
+    type Key;
+    type Value;
+    /// Each entry as a `[key, value]` list, borrowing the items when the map is borrowed
+    fn into_entry_values<'a>(self) -> impl Iterator<Item = FieldValue<'a>>
+    where
+        Self: 'a;
+}
+
+/// A map that can be turned into a `JSON` object for output
+pub trait JsonMap {
+    fn into_json_object(self) -> IndexMap<Name, Value>;
+}
+
+/// The GraphQL representation of a map, selected by `#[graphql(map_as = "...")]`
+pub trait MapRepr {
+    type Output<M>;
+    fn wrap<M>(map: M) -> Self::Output<M>;
+}
+
+/// `map_as = "entries"`: a list of `KeyValue_T` objects
+pub struct AsEntries;
+
+/// `map_as = "json"`: a `JSON` scalar
+pub struct AsJson;
+
+impl MapRepr for AsEntries {
+    type Output<M> = MapEntries<M>;
+    fn wrap<M>(map: M) -> Self::Output<M> {
+        MapEntries(map)
+    }
+}
+
+impl MapRepr for AsJson {
+    type Output<M> = MapJson<M>;
+    fn wrap<M>(map: M) -> Self::Output<M> {
+        MapJson(map)
+    }
+}
+
+pub trait IntoMapOutput<R: MapRepr> {
+    type Output;
+    fn into_map_output(self) -> Self::Output;
+}
+
+macro_rules! output_maps {
+    ($($map:ident<K, V $(, $s:ident)?>),*) => {
+        $(
+            impl<K, V $(, $s)?> OutputMap for $map<K, V $(, $s)?>
+            where
+                K: Send + Sync + 'static,
+                V: Send + Sync + 'static,
+            {
+                type Key = K;
+                type Value = V;
+                fn into_entry_values<'a>(self) -> impl Iterator<Item = FieldValue<'a>>
+                where
+                    Self: 'a,
+                {
+                    self.into_iter().map(|(key, value)| {
+                        FieldValue::list([FieldValue::owned_any(key), FieldValue::owned_any(value)])
+                    })
+                }
+            }
+            impl<K, V $(, $s)?> OutputMap for &$map<K, V $(, $s)?>
+            where
+                K: Send + Sync + 'static,
+                V: Send + Sync + 'static,
+            {
+                type Key = K;
+                type Value = V;
+                fn into_entry_values<'a>(self) -> impl Iterator<Item = FieldValue<'a>>
+                where
+                    Self: 'a,
+                {
+                    self.iter().map(|(key, value)| {
+                        FieldValue::list([FieldValue::borrowed_any(key), FieldValue::borrowed_any(value)])
+                    })
+                }
+            }
+            impl<K: ToString, V: Into<Value> $(, $s)?> JsonMap for $map<K, V $(, $s)?> {
+                fn into_json_object(self) -> IndexMap<Name, Value> {
+                    self.into_iter()
+                        .map(|(key, value)| (Name::new(key.to_string()), value.into()))
+                        .collect()
+                }
+            }
+            // a `Value` owns its data, so the values of a borrowed map are cloned
+            impl<K: ToString, V: Clone + Into<Value> $(, $s)?> JsonMap for &$map<K, V $(, $s)?> {
+                fn into_json_object(self) -> IndexMap<Name, Value> {
+                    self.iter()
+                        .map(|(key, value)| (Name::new(key.to_string()), value.clone().into()))
+                        .collect()
+                }
+            }
+            impl<R: MapRepr, K, V $(, $s)?> IntoMapOutput<R> for $map<K, V $(, $s)?> {
+                type Output = R::Output<Self>;
+                #[inline]
+                fn into_map_output(self) -> Self::Output {
+                    R::wrap(self)
+                }
+            }
+            impl<'a, R: MapRepr, K, V $(, $s)?> IntoMapOutput<R> for &'a $map<K, V $(, $s)?> {
+                type Output = R::Output<Self>;
+                #[inline]
+                fn into_map_output(self) -> Self::Output {
+                    R::wrap(self)
+                }
+            }
+        )*
+    };
+}
+
+output_maps!(HashMap<K, V, S>, BTreeMap<K, V>, IndexMap<K, V, S>);
+
+impl<R: MapRepr, T: IntoMapOutput<R>> IntoMapOutput<R> for Option<T> {
+    type Output = Option<T::Output>;
+    #[inline]
+    fn into_map_output(self) -> Self::Output {
+        self.map(IntoMapOutput::into_map_output)
+    }
+}
+
+impl<'a, R: MapRepr, T> IntoMapOutput<R> for &'a Option<T>
+where
+    &'a T: IntoMapOutput<R>,
+{
+    type Output = Option<<&'a T as IntoMapOutput<R>>::Output>;
+    #[inline]
+    fn into_map_output(self) -> Self::Output {
+        self.as_ref().map(IntoMapOutput::into_map_output)
+    }
+}
+
+impl<R: MapRepr, T: IntoMapOutput<R>, E> IntoMapOutput<R> for Result<T, E> {
+    type Output = Result<T::Output, E>;
+    #[inline]
+    fn into_map_output(self) -> Self::Output {
+        self.map(IntoMapOutput::into_map_output)
+    }
+}
+
+/// The `{ key, value }` object of a map represented as entries
+///
+/// Entries are resolved as `[key, value]` lists by [`OutputMap::into_entry_values`],
+/// so the items of a borrowed map are not cloned.
+pub struct MapEntry<K, V>(PhantomData<(K, V)>);
+
+/// The name of a value type, e.g. `[Int!]` becomes `ListOfInt`
+fn type_ref_name(type_ref: TypeRefBuilder) -> String {
+    match type_ref {
+        TypeRefBuilder::NamedNN(name) => name,
+        TypeRefBuilder::Named(name) => format!("Optional{}", name),
+        TypeRefBuilder::NNListNN(name) => format!("ListOf{}", name),
+        TypeRefBuilder::NNList(name) => format!("OptionalListOf{}", name),
+        TypeRefBuilder::ListNN(name) => format!("ListOfOptional{}", name),
+        TypeRefBuilder::List(name) => format!("OptionalListOfOptional{}", name),
+    }
+}
+
+/// The `index` item of the `[key, value]` entry resolved by the parent field
+fn entry_item<'a, T: Any>(entry: &'a FieldValue<'_>, index: usize) -> Result<&'a T> {
+    entry
+        .try_to_list()?
+        .get(index)
+        .ok_or_else(|| Error::new("internal: invalid map entry"))?
+        .try_downcast_ref::<T>()
+}
+
+impl<K, V> TypeName for MapEntry<K, V>
+where
+    K: OutputTypeName + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+    V: GetOutputTypeRef + Register + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+{
+    fn get_type_name() -> Cow<'static, str> {
+        let key = K::get_output_type_name();
+        let value = type_ref_name(V::get_output_type_ref());
+        if key == dynamic::TypeRef::STRING {
+            format!("KeyValue_{}", value).into()
+        } else {
+            format!("KeyValue_{}_{}", key, value).into()
+        }
+    }
+}
+
+impl<K, V> OutputTypeName for MapEntry<K, V>
+where
+    K: OutputTypeName + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+    V: GetOutputTypeRef + Register + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+{
+}
+
+impl<K, V> ParentType for MapEntry<K, V>
+where
+    K: OutputTypeName + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+    V: GetOutputTypeRef + Register + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+{
+    type Type = Self;
+}
+
+impl<K, V> Object for MapEntry<K, V>
+where
+    K: OutputTypeName + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+    V: GetOutputTypeRef + Register + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+{
+}
+
+impl<K, V> Register for MapEntry<K, V>
+where
+    K: OutputTypeName + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+    V: GetOutputTypeRef + Register + for<'a> ResolveRef<'a> + Send + Sync + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        let registry = registry.register::<K>().register::<V>();
+        let object = dynamic::Object::new(<Self as Object>::get_object_type_name().as_ref());
+        let key = dynamic::Field::new("key", K::get_output_type_ref(), |ctx| {
+            dynamic::FieldFuture::new(async move {
+                let key = entry_item::<K>(ctx.parent_value, 0)?;
+                Resolve::resolve(key, &ctx)
+            })
+        });
+        let value = dynamic::Field::new("value", V::get_output_type_ref(), |ctx| {
+            dynamic::FieldFuture::new(async move {
+                let value = entry_item::<V>(ctx.parent_value, 1)?;
+                Resolve::resolve(value, &ctx)
+            })
+        });
+        registry.register_type(object.field(key).field(value))
+    }
+}
+
+/// A map resolved as a list of [`MapEntry`] objects
+pub struct MapEntries<M>(pub M);
+
+impl<M> Register for MapEntries<M>
+where
+    M: OutputMap,
+    MapEntry<M::Key, M::Value>: Register + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        registry.register::<MapEntry<M::Key, M::Value>>()
+    }
+}
+
+impl<M> GetOutputTypeRef for MapEntries<M>
+where
+    M: OutputMap,
+    MapEntry<M::Key, M::Value>: OutputTypeName,
+{
+    #[inline]
+    fn get_output_type_ref() -> TypeRefBuilder {
+        <MapEntry<M::Key, M::Value>>::get_output_type_ref().list()
+    }
+}
+
+impl<'a, M> ResolveOwned<'a> for MapEntries<M>
+where
+    M: OutputMap + 'a,
+{
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::list(self.0.into_entry_values())))
+    }
+}
+
+/// A map resolved as a `JSON` scalar object
+pub struct MapJson<M>(pub M);
+
+struct Json;
+
+impl Register for Json {
+    fn register(registry: Registry) -> Registry {
+        registry.register_type(dynamic::Scalar::new(JSON))
+    }
+}
+
+impl<M> Register for MapJson<M> {
+    fn register(registry: Registry) -> Registry {
+        registry.register::<Json>()
+    }
+}
+
+impl<M> GetOutputTypeRef for MapJson<M> {
+    #[inline]
+    fn get_output_type_ref() -> TypeRefBuilder {
+        TypeRefBuilder::NamedNN(JSON.to_string())
+    }
+}
+
+impl<'a, M> ResolveOwned<'a> for MapJson<M>
+where
+    M: JsonMap,
+{
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(Value::Object(
+            self.0.into_json_object(),
+        ))))
+    }
+}
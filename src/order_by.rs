@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use crate::Name;
+use crate::Result;
+use crate::Value;
+use crate::dynamic;
+use crate::errors::InputValueError;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::types::InputTypeName;
+use crate::types::Register;
+use crate::types::TypeName;
+
+/// The direction of an order by key, `ASC` by default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    /// The name of the direction in the `SortDirection` enum
+    pub fn name(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+
+    /// Apply the direction to an ascending ordering
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+}
+
+impl From<SortDirection> for Value {
+    fn from(value: SortDirection) -> Self {
+        Value::Enum(Name::new(value.name()))
+    }
+}
+
+impl TypeName for SortDirection {
+    fn get_type_name() -> Cow<'static, str> {
+        "SortDirection".into()
+    }
+}
+
+impl InputTypeName for SortDirection {}
+
+impl Register for SortDirection {
+    fn register(registry: Registry) -> Registry {
+        let object = dynamic::Enum::new(<Self as InputTypeName>::get_input_type_name().as_ref())
+            .item(dynamic::EnumItem::new(SortDirection::Asc.name()))
+            .item(dynamic::EnumItem::new(SortDirection::Desc.name()));
+        registry.register_type(object)
+    }
+}
+
+impl FromValue for SortDirection {
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        let value = value?;
+        match value.enum_name()? {
+            "ASC" => Ok(SortDirection::Asc),
+            "DESC" => Ok(SortDirection::Desc),
+            name => Err(InputValueError::custom(format!(
+                "Unknown variant `{}` for enum `{}`",
+                name,
+                <Self as InputTypeName>::get_input_type_name()
+            ))),
+        }
+    }
+}
+
+/// An order by input that can compare values of `T`
+///
+/// Lists of keys compare by the first key, falling back to the next ones on ties.
+pub trait OrderBy<T> {
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+
+    /// Sort `items` in place, keeping the original order of equal items
+    fn sort(&self, items: &mut [T]) {
+        items.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+impl<T, O: OrderBy<T>> OrderBy<T> for [O] {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.iter().fold(Ordering::Equal, |ordering, key| {
+            ordering.then_with(|| key.compare(a, b))
+        })
+    }
+}
+
+impl<T, O: OrderBy<T>> OrderBy<T> for Vec<O> {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.as_slice().compare(a, b)
+    }
+}
+
+impl<T, O: OrderBy<T>> OrderBy<T> for Option<O> {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        match self {
+            Some(order_by) => order_by.compare(a, b),
+            None => Ordering::Equal,
+        }
+    }
+}
+
+/// Compare two field values, treating incomparable values (e.g. `NaN`) as equal
+pub fn compare_order_values<T: PartialOrd + ?Sized>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
@@ -1,13 +1,19 @@
+use std::any::Any;
 use std::any::TypeId;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::mem;
+use std::rc::Rc;
 
 use crate::data::SchemaData;
 use crate::dynamic;
+use crate::types::Interface;
 use crate::types::Register;
 
+/// Casts the parent value of an object field to an interface the object implements
+pub type InterfaceCast<I> = for<'a> fn(&'a dynamic::FieldValue<'_>) -> async_graphql::Result<&'a I>;
+
 pub struct Registry {
     pub data: SchemaData,
     root: Option<String>,
@@ -18,6 +24,17 @@ pub struct Registry {
     // name of all registered types
     names: HashSet<TypeId>,
     pending_expand_objects: VecDeque<PendingExpandObject>,
+    // objects implementing each interface, with the cast of their values
+    implementations: HashMap<String, Vec<Implementation>>,
+    // objects marked with each interface, they define its fields themselves
+    interface_marks: HashMap<String, Vec<String>>,
+    pending_expand_interfaces: Vec<PendingExpandInterface>,
+    pending_expand_unions: Vec<PendingExpandUnion>,
+    pending_expand_input_objects: Vec<PendingExpandInputObject>,
+    // field names of each input object, `dynamic::InputObject` doesn't expose them
+    input_object_fields: HashMap<String, Vec<&'static str>>,
+    // members of each `errors_as_data` union
+    result_unions: HashMap<String, Vec<String>>,
 }
 
 impl Default for Registry {
@@ -37,6 +54,13 @@ impl Registry {
             types: Default::default(),
             names: Default::default(),
             pending_expand_objects: Default::default(),
+            implementations: Default::default(),
+            interface_marks: Default::default(),
+            pending_expand_interfaces: Default::default(),
+            pending_expand_unions: Default::default(),
+            pending_expand_input_objects: Default::default(),
+            input_object_fields: Default::default(),
+            result_unions: Default::default(),
         }
     }
 }
@@ -47,6 +71,33 @@ struct PendingExpandObject {
     map_fn: Box<dyn FnOnce(dynamic::Object) -> dynamic::Object>,
 }
 
+struct Implementation {
+    object: String,
+    cast: Rc<dyn Any>,
+}
+
+type ExpandImplementationFn = Rc<dyn Fn(dynamic::Object, &dyn Any) -> dynamic::Object>;
+
+struct PendingExpandInterface {
+    target: String,
+    expansion: String,
+    interface_fn: Box<dyn FnOnce(dynamic::Interface) -> dynamic::Interface>,
+    object_fn: ExpandImplementationFn,
+}
+
+struct PendingExpandUnion {
+    target: String,
+    expansion: String,
+    map_fn: Box<dyn FnOnce(dynamic::Union) -> dynamic::Union>,
+}
+
+struct PendingExpandInputObject {
+    target: String,
+    expansion: String,
+    field_names: Vec<&'static str>,
+    map_fn: Box<dyn FnOnce(dynamic::InputObject) -> dynamic::InputObject>,
+}
+
 impl Registry {
     #[inline]
     pub fn set_root(mut self, name: &str) -> Self {
@@ -75,6 +126,26 @@ impl Registry {
         }
         self
     }
+    pub fn register_result_union(mut self, name: &str, members: Vec<String>) -> Self {
+        if let Some(registered) = self.result_unions.get(name) {
+            if *registered != members {
+                panic!(
+                    "Union \"{}\" is defined more than once with different members: [{}] and [{}], use `result_union_name` to rename one of them",
+                    name,
+                    registered.join(", "),
+                    members.join(", "),
+                );
+            }
+            return self;
+        }
+        let union = members
+            .iter()
+            .fold(dynamic::Union::new(name), |union, member| {
+                union.possible_type(member)
+            });
+        self.result_unions.insert(name.to_string(), members);
+        self.register_type(union)
+    }
     pub fn update_object<F>(mut self, target: &str, expansion_name: &str, f: F) -> Self
     where
         F: FnOnce(dynamic::Object) -> dynamic::Object + 'static,
@@ -86,6 +157,91 @@ impl Registry {
         });
         self
     }
+    pub fn update_union<F>(mut self, target: &str, expansion_name: &str, f: F) -> Self
+    where
+        F: FnOnce(dynamic::Union) -> dynamic::Union + 'static,
+    {
+        self.pending_expand_unions.push(PendingExpandUnion {
+            target: target.to_string(),
+            expansion: expansion_name.to_string(),
+            map_fn: Box::new(f),
+        });
+        self
+    }
+    pub fn set_input_object_fields(mut self, name: &str, field_names: Vec<&'static str>) -> Self {
+        self.input_object_fields
+            .insert(name.to_string(), field_names);
+        self
+    }
+    pub fn update_input_object<F>(
+        mut self,
+        target: &str,
+        expansion_name: &str,
+        field_names: Vec<&'static str>,
+        f: F,
+    ) -> Self
+    where
+        F: FnOnce(dynamic::InputObject) -> dynamic::InputObject + 'static,
+    {
+        self.pending_expand_input_objects
+            .push(PendingExpandInputObject {
+                target: target.to_string(),
+                expansion: expansion_name.to_string(),
+                field_names,
+                map_fn: Box::new(f),
+            });
+        self
+    }
+    pub fn add_implementation<I>(mut self, object: &str, cast: InterfaceCast<I>) -> Self
+    where
+        I: Interface + ?Sized + 'static,
+    {
+        let implementations = self
+            .implementations
+            .entry(I::get_interface_type_name().to_string())
+            .or_default();
+        if !implementations.iter().any(|i| i.object == object) {
+            implementations.push(Implementation {
+                object: object.to_string(),
+                cast: Rc::new(cast),
+            });
+        }
+        self
+    }
+    pub fn add_interface_mark<I>(mut self, object: &str) -> Self
+    where
+        I: Interface + ?Sized + 'static,
+    {
+        self.interface_marks
+            .entry(I::get_interface_type_name().to_string())
+            .or_default()
+            .push(object.to_string());
+        self
+    }
+    pub fn update_interface<I, F, G>(
+        mut self,
+        expansion_name: &str,
+        interface_fn: F,
+        object_fn: G,
+    ) -> Self
+    where
+        I: Interface + ?Sized + 'static,
+        F: FnOnce(dynamic::Interface) -> dynamic::Interface + 'static,
+        G: Fn(dynamic::Object, InterfaceCast<I>) -> dynamic::Object + 'static,
+    {
+        self.pending_expand_interfaces.push(PendingExpandInterface {
+            target: I::get_interface_type_name().to_string(),
+            expansion: expansion_name.to_string(),
+            interface_fn: Box::new(interface_fn),
+            object_fn: Rc::new(move |object, cast| {
+                let cast = cast
+                    .downcast_ref::<InterfaceCast<I>>()
+                    .expect("interface cast of another interface");
+                object_fn(object, *cast)
+            }),
+        });
+        self
+    }
 }
 
 impl Registry {
@@ -98,6 +254,92 @@ impl Registry {
         T::register(self)
     }
 
+    fn apply_pending_unions(&mut self) {
+        for pending in mem::take(&mut self.pending_expand_unions) {
+            let position = self.types.iter().position(|ty| {
+                matches!(ty, dynamic::Type::Union(union) if union.type_name() == pending.target)
+            });
+            let Some(position) = position else {
+                panic!(
+                    "Can't find union: {} when defining {}",
+                    pending.target, pending.expansion
+                );
+            };
+            if let dynamic::Type::Union(union) = self.types.remove(position) {
+                self.types.insert(position, (pending.map_fn)(union).into());
+            }
+        }
+    }
+
+    fn apply_pending_input_objects(&mut self) {
+        for pending in mem::take(&mut self.pending_expand_input_objects) {
+            let position = self.types.iter().position(|ty| {
+                matches!(ty, dynamic::Type::InputObject(object) if object.type_name() == pending.target)
+            });
+            let Some(position) = position else {
+                panic!(
+                    "Can't find input object: {} when defining {}",
+                    pending.target, pending.expansion
+                );
+            };
+            let field_names = self
+                .input_object_fields
+                .entry(pending.target.clone())
+                .or_default();
+            for name in pending.field_names {
+                if field_names.contains(&name) {
+                    panic!(
+                        "Field \"{}\" of \"{}\" is defined more than once, check the expansion {}",
+                        name, pending.target, pending.expansion
+                    );
+                }
+                field_names.push(name);
+            }
+            if let dynamic::Type::InputObject(object) = self.types.remove(position) {
+                self.types.insert(position, (pending.map_fn)(object).into());
+            }
+        }
+    }
+
+    fn apply_pending_interfaces(&mut self) {
+        for pending in mem::take(&mut self.pending_expand_interfaces) {
+            let PendingExpandInterface {
+                target,
+                expansion,
+                interface_fn,
+                object_fn,
+            } = pending;
+            let position = self.types.iter().position(|ty| {
+                matches!(ty, dynamic::Type::Interface(interface) if interface.type_name() == target)
+            });
+            let Some(position) = position else {
+                panic!(
+                    "Can't find interface: {} when defining {}",
+                    target, expansion
+                );
+            };
+            // a marked object has no cast to resolve the fields of the expansion
+            if let Some(object) = self.interface_marks.get(&target).and_then(|m| m.first()) {
+                panic!(
+                    "Object \"{}\" is marked with interface \"{}\" and can't resolve the fields of {}, use `implements` instead of `mark`",
+                    object, target, expansion
+                );
+            }
+            if let dynamic::Type::Interface(interface) = self.types.remove(position) {
+                self.types.insert(position, interface_fn(interface).into());
+            }
+            for implementation in self.implementations.get(&target).into_iter().flatten() {
+                let object_fn = object_fn.clone();
+                let cast = implementation.cast.clone();
+                self.pending_expand_objects.push_back(PendingExpandObject {
+                    target: implementation.object.clone(),
+                    expansion: expansion.clone(),
+                    map_fn: Box::new(move |object| object_fn(object, cast.as_ref())),
+                });
+            }
+        }
+    }
+
     fn apply_pending_objects(&mut self) {
         loop {
             if self.pending_expand_objects.is_empty() {
@@ -142,6 +384,9 @@ impl Registry {
         mut self,
         schema: dynamic::SchemaBuilder,
     ) -> dynamic::SchemaBuilder {
+        self.apply_pending_unions();
+        self.apply_pending_input_objects();
+        self.apply_pending_interfaces();
         self.apply_pending_objects();
         let schema = self
             .objects
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::fmt::Display;
 
 use crate::Context;
 use crate::Error;
@@ -18,6 +19,26 @@ pub trait Resolve<'a> {
     fn resolve(self, ctx: &Context) -> Result<Option<FieldValue<'a>>>;
 }
 
+/// Resolve each item of the iterator as a list item
+pub(crate) fn resolve_list<'a, I>(iter: I, ctx: &Context) -> Result<Option<FieldValue<'a>>>
+where
+    I: IntoIterator,
+    I::Item: Resolve<'a>,
+{
+    let items = iter
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let ctx_idx = ctx.with_index(index);
+            match item.resolve(&ctx_idx) {
+                Ok(Some(value)) => value,
+                _ => FieldValue::NULL,
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(Some(FieldValue::list(items)))
+}
+
 mod resolve_ref {
     use super::*;
     // &Option<T>
@@ -40,15 +61,7 @@ mod resolve_ref {
         &'a T: Resolve<'a> + 'a,
     {
         fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list(self, ctx)
         }
     }
     // &ID
@@ -127,15 +140,7 @@ mod resolve_own {
         T: Resolve<'a>,
     {
         fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.into_iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list(self, ctx)
         }
     }
 
@@ -145,15 +150,7 @@ mod resolve_own {
         &'a T: Resolve<'a>,
     {
         fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
-            let iter = self.iter();
-            let items = iter.enumerate().map(|(index, item)| {
-                let ctx_idx = ctx.with_index(index);
-                match item.resolve(&ctx_idx) {
-                    Ok(Some(value)) => value,
-                    _ => FieldValue::NULL,
-                }
-            });
-            Ok(Some(FieldValue::list(items)))
+            resolve_list(self, ctx)
         }
     }
 
@@ -201,6 +198,59 @@ macro_rules! resolves {
     };
 }
 
-resolves!(
-    String, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, bool, f32, f64
-);
+resolves!(String, i8, i16, i32, u8, u16, bool, f32, f64);
+
+/// How to resolve integers that don't fit in the 32-bit GraphQL `Int`.
+///
+/// Add it to the schema data to change the default:
+/// `App::create_schema().data(IntOutputPolicy::Error)`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IntOutputPolicy {
+    /// Resolve the value as is, even if it is out of range
+    #[default]
+    Allow,
+    /// Resolve the field as an error
+    Error,
+    /// Resolve the nearest value in range
+    Clamp,
+}
+
+fn resolve_int<'a, T>(value: T, ctx: &Context) -> Result<Option<FieldValue<'a>>>
+where
+    T: Copy + Display + Into<async_graphql::Value> + TryInto<i32> + PartialOrd + Default,
+{
+    if let Ok(value) = value.try_into() {
+        return Ok(Some(FieldValue::value(value)));
+    }
+    let policy = ctx.data_opt::<IntOutputPolicy>().copied();
+    match policy.unwrap_or_default() {
+        IntOutputPolicy::Allow => Ok(Some(FieldValue::value(value))),
+        IntOutputPolicy::Error => Err(Error::new(format!(
+            "Int cannot represent non 32-bit signed integer value: {}",
+            value
+        ))),
+        IntOutputPolicy::Clamp if value > T::default() => Ok(Some(FieldValue::value(i32::MAX))),
+        IntOutputPolicy::Clamp => Ok(Some(FieldValue::value(i32::MIN))),
+    }
+}
+
+macro_rules! resolves_int {
+    ($($ty:ident),*) => {
+        $(
+            impl <'a> ResolveOwned<'a> for $ty {
+                #[inline]
+                fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_int(self, ctx)
+                }
+            }
+            impl <'a> ResolveRef<'a> for $ty {
+                #[inline]
+                fn resolve_ref(&self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_int(*self, ctx)
+                }
+            }
+        )*
+    };
+}
+
+resolves_int!(i64, isize, u32, u64, usize);
@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+use crate::registry::Registry;
+use crate::resolve::ResolveOwned;
+use crate::types::Object;
+use crate::types::Register;
+
+/// The member types of a union, implemented by `#[derive(Union)]`
+pub trait UnionMembers {
+    /// Register the member types without the union itself
+    fn register_members(registry: Registry) -> Registry;
+
+    /// The object type names of the members
+    fn get_member_type_names() -> Vec<Cow<'static, str>>;
+}
+
+/// The value of a `#[graphql(errors_as_data)]` field
+///
+/// `Ok` resolves as `T` and `Err` as the member of the `E` union it holds.
+pub struct ResultUnion<T, E>(pub Result<T, E>);
+
+impl<T, E> From<Result<T, E>> for ResultUnion<T, E> {
+    fn from(value: Result<T, E>) -> Self {
+        ResultUnion(value)
+    }
+}
+
+impl<'a, T, E> ResolveOwned<'a> for ResultUnion<T, E>
+where
+    T: ResolveOwned<'a> + Object,
+    E: ResolveOwned<'a>,
+{
+    fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        match self.0 {
+            Ok(value) => value
+                .resolve_owned(ctx)
+                .map(|value| value.map(|value| value.with_type(T::get_object_type_name()))),
+            Err(error) => error.resolve_owned(ctx),
+        }
+    }
+}
+
+/// Register the `name` union of `T` and the members of `E`
+pub fn register_result_union<T, E>(registry: Registry, name: &str) -> Registry
+where
+    T: Register + Object + 'static,
+    E: UnionMembers,
+{
+    let registry = registry.register::<T>();
+    let registry = E::register_members(registry);
+    let members = std::iter::once(T::get_object_type_name())
+        .chain(E::get_member_type_names())
+        .map(|member| member.into_owned())
+        .collect();
+    registry.register_result_union(name, members)
+}
@@ -0,0 +1,361 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use async_graphql::Number;
+
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+use crate::Value;
+use crate::dynamic;
+use crate::errors::InputValueError;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::types::InputTypeName;
+use crate::types::OutputTypeName;
+use crate::types::Register;
+use crate::types::TypeName;
+
+/// A GraphQL scalar, possibly shared by several rust types
+trait ScalarDef: 'static {
+    const NAME: &'static str;
+    const SPECIFIED_BY_URL: Option<&'static str> = None;
+}
+
+/// A rust type from another crate represented as a string scalar
+trait StringScalar: Sized {
+    type Def: ScalarDef;
+    /// accept number literals as input as well as strings
+    const ACCEPT_NUMBER: bool = false;
+    fn to_scalar_string(&self) -> String;
+    fn from_scalar_str(value: &str) -> std::result::Result<Self, String>;
+    fn from_scalar_number(value: &Number) -> std::result::Result<Self, String> {
+        Self::from_scalar_str(&value.to_string())
+    }
+}
+
+struct RegisterScalar<D>(PhantomData<D>);
+
+impl<D: ScalarDef> Register for RegisterScalar<D> {
+    fn register(registry: Registry) -> Registry {
+        let scalar = dynamic::Scalar::new(D::NAME);
+        let scalar = match D::SPECIFIED_BY_URL {
+            Some(url) => scalar.specified_by_url(url),
+            None => scalar,
+        };
+        registry.register_type(scalar)
+    }
+}
+
+fn parse_scalar<T: StringScalar + InputTypeName>(
+    value: Result<dynamic::ValueAccessor>,
+) -> InputValueResult<T> {
+    let value = value?;
+    match value.as_value() {
+        Value::Number(number) if T::ACCEPT_NUMBER => T::from_scalar_number(number),
+        _ => T::from_scalar_str(value.string()?),
+    }
+    .map_err(InputValueError::custom)
+}
+
+macro_rules! scalar_defs {
+    ($($(#[$meta:meta])* $def:ident => $name:literal $(, $url:literal)?;)*) => {
+        $(
+            $(#[$meta])*
+            struct $def;
+            $(#[$meta])*
+            impl ScalarDef for $def {
+                const NAME: &'static str = $name;
+                $(const SPECIFIED_BY_URL: Option<&'static str> = Some($url);)?
+            }
+        )*
+    };
+}
+
+macro_rules! string_scalars {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl TypeName for $ty {
+                fn get_type_name() -> Cow<'static, str> {
+                    <<$ty as StringScalar>::Def as ScalarDef>::NAME.into()
+                }
+            }
+            impl InputTypeName for $ty {}
+            impl OutputTypeName for $ty {}
+            impl Register for $ty {
+                fn register(registry: Registry) -> Registry {
+                    registry.register::<RegisterScalar<<$ty as StringScalar>::Def>>()
+                }
+            }
+            impl FromValue for $ty {
+                fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+                    parse_scalar(value)
+                }
+            }
+            impl<'a> ResolveOwned<'a> for $ty {
+                #[inline]
+                fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    Ok(Some(FieldValue::value(self.to_scalar_string())))
+                }
+            }
+            impl<'a> ResolveRef<'a> for $ty {
+                #[inline]
+                fn resolve_ref(&self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    Ok(Some(FieldValue::value(self.to_scalar_string())))
+                }
+            }
+        )*
+    };
+}
+
+scalar_defs! {
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    DateTimeDef => "DateTime", "https://datatracker.ietf.org/doc/html/rfc3339";
+    #[cfg(feature = "chrono")]
+    NaiveDateDef => "NaiveDate", "https://datatracker.ietf.org/doc/html/rfc3339#section-5.6";
+    #[cfg(feature = "chrono")]
+    NaiveTimeDef => "NaiveTime", "https://datatracker.ietf.org/doc/html/rfc3339#section-5.6";
+    #[cfg(feature = "chrono")]
+    NaiveDateTimeDef => "NaiveDateTime", "https://en.wikipedia.org/wiki/ISO_8601";
+    #[cfg(feature = "time")]
+    DateDef => "Date", "https://datatracker.ietf.org/doc/html/rfc3339#section-5.6";
+    #[cfg(feature = "time")]
+    PrimitiveDateTimeDef => "PrimitiveDateTime", "https://en.wikipedia.org/wiki/ISO_8601";
+    #[cfg(feature = "uuid")]
+    UuidDef => "UUID", "https://datatracker.ietf.org/doc/html/rfc4122";
+    #[cfg(feature = "url")]
+    UrlDef => "Url", "https://url.spec.whatwg.org/";
+    #[cfg(feature = "rust_decimal")]
+    DecimalDef => "Decimal";
+    #[cfg(feature = "bigdecimal")]
+    BigDecimalDef => "BigDecimal";
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_scalars {
+    use chrono::DateTime;
+    use chrono::FixedOffset;
+    use chrono::NaiveDate;
+    use chrono::NaiveDateTime;
+    use chrono::NaiveTime;
+    use chrono::SecondsFormat;
+    use chrono::Utc;
+
+    use super::*;
+
+    const NAIVE_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+    impl StringScalar for DateTime<Utc> {
+        type Def = DateTimeDef;
+        fn to_scalar_string(&self) -> String {
+            self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            DateTime::parse_from_rfc3339(value)
+                .map(|value| value.with_timezone(&Utc))
+                .map_err(|err| err.to_string())
+        }
+    }
+
+    impl StringScalar for DateTime<FixedOffset> {
+        type Def = DateTimeDef;
+        fn to_scalar_string(&self) -> String {
+            self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            DateTime::parse_from_rfc3339(value).map_err(|err| err.to_string())
+        }
+    }
+
+    impl StringScalar for NaiveDate {
+        type Def = NaiveDateDef;
+        fn to_scalar_string(&self) -> String {
+            self.to_string()
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            value
+                .parse()
+                .map_err(|err: chrono::ParseError| err.to_string())
+        }
+    }
+
+    impl StringScalar for NaiveTime {
+        type Def = NaiveTimeDef;
+        fn to_scalar_string(&self) -> String {
+            self.to_string()
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            value
+                .parse()
+                .map_err(|err: chrono::ParseError| err.to_string())
+        }
+    }
+
+    impl StringScalar for NaiveDateTime {
+        type Def = NaiveDateTimeDef;
+        fn to_scalar_string(&self) -> String {
+            self.format(NAIVE_DATE_TIME_FORMAT).to_string()
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            NaiveDateTime::parse_from_str(value, NAIVE_DATE_TIME_FORMAT)
+                .map_err(|err| err.to_string())
+        }
+    }
+
+    string_scalars!(
+        DateTime<Utc>,
+        DateTime<FixedOffset>,
+        NaiveDate,
+        NaiveTime,
+        NaiveDateTime,
+    );
+}
+
+#[cfg(feature = "time")]
+mod time_scalars {
+    use time::Date;
+    use time::OffsetDateTime;
+    use time::PrimitiveDateTime;
+    use time::format_description::BorrowedFormatItem;
+    use time::format_description::well_known::Rfc3339;
+    use time::macros::format_description;
+
+    use super::*;
+
+    const DATE_FORMAT: &[BorrowedFormatItem<'static>] = format_description!("[year]-[month]-[day]");
+    const PRIMITIVE_DATE_TIME_FORMAT: &[BorrowedFormatItem<'static>] = format_description!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]"
+    );
+
+    impl StringScalar for OffsetDateTime {
+        type Def = DateTimeDef;
+        fn to_scalar_string(&self) -> String {
+            self.format(&Rfc3339).unwrap_or_else(|_| self.to_string())
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            OffsetDateTime::parse(value, &Rfc3339).map_err(|err| err.to_string())
+        }
+    }
+
+    impl StringScalar for Date {
+        type Def = DateDef;
+        fn to_scalar_string(&self) -> String {
+            self.format(DATE_FORMAT)
+                .unwrap_or_else(|_| self.to_string())
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            Date::parse(value, DATE_FORMAT).map_err(|err| err.to_string())
+        }
+    }
+
+    impl StringScalar for PrimitiveDateTime {
+        type Def = PrimitiveDateTimeDef;
+        fn to_scalar_string(&self) -> String {
+            self.format(PRIMITIVE_DATE_TIME_FORMAT)
+                .unwrap_or_else(|_| self.to_string())
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            PrimitiveDateTime::parse(value, PRIMITIVE_DATE_TIME_FORMAT)
+                .map_err(|err| err.to_string())
+        }
+    }
+
+    string_scalars!(OffsetDateTime, Date, PrimitiveDateTime);
+}
+
+#[cfg(feature = "uuid")]
+mod uuid_scalars {
+    use uuid::Uuid;
+
+    use super::*;
+
+    impl StringScalar for Uuid {
+        type Def = UuidDef;
+        fn to_scalar_string(&self) -> String {
+            self.hyphenated().to_string()
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            Uuid::parse_str(value).map_err(|err| err.to_string())
+        }
+    }
+
+    string_scalars!(Uuid);
+}
+
+#[cfg(feature = "url")]
+mod url_scalars {
+    use url::Url;
+
+    use super::*;
+
+    impl StringScalar for Url {
+        type Def = UrlDef;
+        fn to_scalar_string(&self) -> String {
+            self.to_string()
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            Url::parse(value).map_err(|err| err.to_string())
+        }
+    }
+
+    string_scalars!(Url);
+}
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal_scalars {
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    impl StringScalar for Decimal {
+        type Def = DecimalDef;
+        const ACCEPT_NUMBER: bool = true;
+        fn to_scalar_string(&self) -> String {
+            self.to_string()
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            value
+                .parse()
+                .map_err(|err: rust_decimal::Error| err.to_string())
+        }
+        fn from_scalar_number(value: &Number) -> std::result::Result<Self, String> {
+            if let Some(value) = value.as_i64() {
+                return Ok(value.into());
+            }
+            if let Some(value) = value.as_u64() {
+                return Ok(value.into());
+            }
+            // large floats are printed in exponent form, e.g. `1e21`, which `parse` rejects
+            let value = value.to_string();
+            Self::from_scalar_str(&value)
+                .or_else(|_| Decimal::from_scientific(&value).map_err(|err| err.to_string()))
+        }
+    }
+
+    string_scalars!(Decimal);
+}
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal_scalars {
+    use bigdecimal::BigDecimal;
+
+    use super::*;
+
+    impl StringScalar for BigDecimal {
+        type Def = BigDecimalDef;
+        const ACCEPT_NUMBER: bool = true;
+        fn to_scalar_string(&self) -> String {
+            self.to_string()
+        }
+        fn from_scalar_str(value: &str) -> std::result::Result<Self, String> {
+            value
+                .parse()
+                .map_err(|err: bigdecimal::ParseBigDecimalError| err.to_string())
+        }
+    }
+
+    string_scalars!(BigDecimal);
+}
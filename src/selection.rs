@@ -0,0 +1,77 @@
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use crate::Context;
+
+/// The fields of an object mapped to its Rust fields, implemented by `#[derive(SimpleObject)]`
+pub trait SelectableFields {
+    /// `(GraphQL name, Rust ident)` of each field, the fields hoisted with `flatten` map to the
+    /// ident of the flattened field
+    fn get_selectable_fields() -> Vec<(&'static str, &'static str)>;
+}
+
+/// The Rust fields of `T` requested in the selection set of the current field
+///
+/// Used by `#[graphql(selection)]` arguments.
+pub struct SelectedFields<T> {
+    idents: Vec<&'static str>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: SelectableFields> SelectedFields<T> {
+    pub fn from_context(ctx: &Context) -> Self {
+        let fields = T::get_selectable_fields();
+        let mut idents = Vec::new();
+        for selection in ctx.field().selection_set() {
+            let ident = fields
+                .iter()
+                .find(|(name, _)| *name == selection.name())
+                .map(|(_, ident)| *ident);
+            if let Some(ident) = ident
+                && !idents.contains(&ident)
+            {
+                idents.push(ident);
+            }
+        }
+        Self {
+            idents,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> SelectedFields<T> {
+    /// Whether the Rust field `ident` is requested
+    pub fn contains(&self, ident: &str) -> bool {
+        self.idents.contains(&ident)
+    }
+
+    /// The requested Rust fields, in the order they were first selected
+    pub fn idents(&self) -> &[&'static str] {
+        &self.idents
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.idents.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.idents.iter().copied()
+    }
+}
+
+impl<T> Clone for SelectedFields<T> {
+    fn clone(&self) -> Self {
+        Self {
+            idents: self.idents.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for SelectedFields<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SelectedFields").field(&self.idents).finish()
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Error;
+use crate::Result;
+use crate::Value;
+
+/// Convert a scalar into a GraphQL value using its `Serialize` implementation.
+///
+/// Values that can't be represented as a GraphQL value are reported as a
+/// resolver error. `ScalarValue::to_value` has no error path and falls back
+/// to `null` for them.
+pub fn to_scalar_value<T: Serialize + ?Sized>(value: &T) -> Result<Value> {
+    async_graphql::to_value(value).map_err(|err| Error::new(err.to_string()))
+}
+
+/// Parse a scalar from a GraphQL value using its `Deserialize` implementation.
+pub fn from_scalar_value<T: DeserializeOwned>(value: Value) -> Result<T> {
+    async_graphql::from_value(value).map_err(|err| Error::new(err.to_string()))
+}
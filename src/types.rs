@@ -1,9 +1,17 @@
 use std::borrow::Cow;
 
+use async_graphql::dynamic;
+
+use crate::flatten::FlattenPath;
 use crate::registry::Registry;
 use crate::type_ref_builder::TypeRefBuilder;
 
+mod collections;
 mod common;
+mod numbers;
+
+pub use collections::List;
+pub use numbers::BigInt;
 
 pub trait Register {
     #[inline]
@@ -75,6 +83,9 @@ pub trait ParentType {
 
 pub trait InterfaceMark<T: Interface + ?Sized> {}
 
+/// Marks an object as a possible type of the union `T`
+pub trait UnionMark<T: Union + ?Sized> {}
+
 pub trait InputObject: InputTypeName {
     fn get_input_object_type_name() -> Cow<'static, str> {
         <Self as InputTypeName>::get_input_type_name()
@@ -87,6 +98,34 @@ pub trait ExpandObject: ParentType {
     fn get_expand_object_name() -> Cow<'static, str>;
 }
 
+/// Input fields added to an input object defined elsewhere
+pub trait ExpandInputObject {
+    type Target: InputObject;
+    fn get_expand_input_object_name() -> Cow<'static, str>;
+}
+
+/// Possible types added to a union defined elsewhere
+pub trait ExpandUnion {
+    type Union: Union + ?Sized;
+    fn get_expand_union_name() -> Cow<'static, str>;
+}
+
+/// Fields added to an interface and to every object implementing it
+///
+/// Objects must use `implements`, the schema can't be built with an object marked with the interface.
+pub trait ExpandInterface {
+    type Interface: Interface + ?Sized + 'static;
+    fn get_expand_interface_name() -> Cow<'static, str>;
+}
+
+/// Resolver methods merged into a `SimpleObject` marked with `#[graphql(complex)]`
+pub trait ComplexObject: Object + Sized {
+    /// names of the fields added by the resolver methods
+    fn get_complex_field_names() -> Vec<&'static str>;
+    fn register_complex_types(registry: Registry) -> Registry;
+    fn define_complex_fields<P: FlattenPath<Self>>(object: dynamic::Object) -> dynamic::Object;
+}
+
 pub trait GetOutputTypeRef {
     fn get_output_type_ref() -> TypeRefBuilder;
 }
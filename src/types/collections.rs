@@ -0,0 +1,252 @@
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use async_graphql::dynamic;
+
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+use crate::errors::InputValueError;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::resolve::Resolve;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::resolve::resolve_list;
+use crate::type_ref_builder::TypeRefBuilder;
+use crate::types::GetInputTypeRef;
+use crate::types::GetOutputTypeRef;
+use crate::types::Register;
+
+/// Any iterable value resolved as a GraphQL list
+///
+/// Use it to return iterators and other collections without collecting them into a `Vec` first,
+/// e.g. `List<std::vec::IntoIter<Foo>>` or `List<Box<dyn Iterator<Item = Foo> + Send>>`.
+pub struct List<I>(pub I);
+
+impl<I> Register for List<I>
+where
+    I: IntoIterator,
+    I::Item: Register + 'static,
+{
+    fn register(registry: Registry) -> Registry {
+        registry.register::<I::Item>()
+    }
+}
+
+impl<I> GetOutputTypeRef for List<I>
+where
+    I: IntoIterator,
+    I::Item: GetOutputTypeRef,
+{
+    #[inline]
+    fn get_output_type_ref() -> TypeRefBuilder {
+        I::Item::get_output_type_ref().list()
+    }
+}
+
+impl<'a, I> ResolveOwned<'a> for List<I>
+where
+    I: IntoIterator,
+    I::Item: Resolve<'a>,
+{
+    fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        resolve_list(self.0, ctx)
+    }
+}
+
+impl<'a, I: 'a> ResolveRef<'a> for List<I>
+where
+    &'a I: IntoIterator,
+    <&'a I as IntoIterator>::Item: Resolve<'a>,
+{
+    fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        resolve_list(&self.0, ctx)
+    }
+}
+
+macro_rules! list_outputs {
+    ($([$($g:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            impl<$($g)*> Register for $ty
+            where
+                T: Register + 'static,
+            {
+                fn register(registry: Registry) -> Registry {
+                    registry.register::<T>()
+                }
+            }
+            impl<$($g)*> GetOutputTypeRef for $ty
+            where
+                T: GetOutputTypeRef,
+            {
+                #[inline]
+                fn get_output_type_ref() -> TypeRefBuilder {
+                    T::get_output_type_ref().list()
+                }
+            }
+            impl<'a, $($g)*> ResolveOwned<'a> for $ty
+            where
+                T: Resolve<'a>,
+            {
+                fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_list(self, ctx)
+                }
+            }
+            impl<'a, $($g)*> ResolveRef<'a> for $ty
+            where
+                &'a T: Resolve<'a> + 'a,
+            {
+                fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    resolve_list(self.iter(), ctx)
+                }
+            }
+        )*
+    };
+}
+
+list_outputs!(
+    [T, const N: usize] [T; N],
+    [T] VecDeque<T>,
+    [T, S] HashSet<T, S>,
+    [T] BTreeSet<T>,
+);
+
+impl<T: GetInputTypeRef, const N: usize> GetInputTypeRef for [T; N] {
+    #[inline]
+    fn get_input_type_ref() -> TypeRefBuilder {
+        T::get_input_type_ref().list()
+    }
+}
+
+impl<T: GetInputTypeRef, S> GetInputTypeRef for HashSet<T, S> {
+    #[inline]
+    fn get_input_type_ref() -> TypeRefBuilder {
+        T::get_input_type_ref().list()
+    }
+}
+
+impl<T: GetInputTypeRef> GetInputTypeRef for BTreeSet<T> {
+    #[inline]
+    fn get_input_type_ref() -> TypeRefBuilder {
+        T::get_input_type_ref().list()
+    }
+}
+
+impl<T, const N: usize> FromValue for [T; N]
+where
+    T: FromValue + GetInputTypeRef,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        let items = Vec::<T>::from_value(value).map_err(InputValueError::propagate)?;
+        let len = items.len();
+        items.try_into().map_err(|_| {
+            InputValueError::custom(format!("Expected a list of {} items, found {}.", N, len))
+        })
+    }
+}
+
+impl<T, S> FromValue for HashSet<T, S>
+where
+    T: FromValue + GetInputTypeRef + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        value?
+            .list()?
+            .iter()
+            .map(|v| T::from_value(Ok(v)).map_err(InputValueError::propagate))
+            .collect()
+    }
+}
+
+impl<T> FromValue for BTreeSet<T>
+where
+    T: FromValue + GetInputTypeRef + Ord,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        value?
+            .list()?
+            .iter()
+            .map(|v| T::from_value(Ok(v)).map_err(InputValueError::propagate))
+            .collect()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec_lists {
+    use smallvec::Array;
+    use smallvec::SmallVec;
+
+    use super::*;
+
+    impl<A> Register for SmallVec<A>
+    where
+        A: Array,
+        A::Item: Register + 'static,
+    {
+        fn register(registry: Registry) -> Registry {
+            registry.register::<A::Item>()
+        }
+    }
+
+    impl<A> GetOutputTypeRef for SmallVec<A>
+    where
+        A: Array,
+        A::Item: GetOutputTypeRef,
+    {
+        #[inline]
+        fn get_output_type_ref() -> TypeRefBuilder {
+            A::Item::get_output_type_ref().list()
+        }
+    }
+
+    impl<A> GetInputTypeRef for SmallVec<A>
+    where
+        A: Array,
+        A::Item: GetInputTypeRef,
+    {
+        #[inline]
+        fn get_input_type_ref() -> TypeRefBuilder {
+            A::Item::get_input_type_ref().list()
+        }
+    }
+
+    impl<'a, A> ResolveOwned<'a> for SmallVec<A>
+    where
+        A: Array,
+        A::Item: Resolve<'a>,
+    {
+        fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list(self, ctx)
+        }
+    }
+
+    impl<'a, A> ResolveRef<'a> for SmallVec<A>
+    where
+        A: Array,
+        &'a A::Item: Resolve<'a> + 'a,
+    {
+        fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+            resolve_list(self.iter(), ctx)
+        }
+    }
+
+    impl<A> FromValue for SmallVec<A>
+    where
+        A: Array,
+        A::Item: FromValue + GetInputTypeRef,
+    {
+        fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+            value?
+                .list()?
+                .iter()
+                .map(|v| A::Item::from_value(Ok(v)).map_err(InputValueError::propagate))
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::num::NonZeroI8;
+use std::num::NonZeroI16;
+use std::num::NonZeroI32;
+use std::num::NonZeroI64;
+use std::num::NonZeroIsize;
+use std::num::NonZeroU8;
+use std::num::NonZeroU16;
+use std::num::NonZeroU32;
+use std::num::NonZeroU64;
+use std::num::NonZeroUsize;
+use std::num::Wrapping;
+use std::str::FromStr;
+
+use async_graphql::dynamic;
+
+use crate::Context;
+use crate::FieldValue;
+use crate::Result;
+use crate::Value;
+use crate::errors::InputValueError;
+use crate::errors::InputValueResult;
+use crate::from_value::FromValue;
+use crate::registry::Registry;
+use crate::resolve::ResolveOwned;
+use crate::resolve::ResolveRef;
+use crate::types::GetInputTypeRef;
+use crate::types::InputTypeName;
+use crate::types::OutputTypeName;
+use crate::types::Register;
+use crate::types::TypeName;
+
+const BIG_INT: &str = "BigInt";
+
+/// An integer resolved as a `BigInt` string scalar
+///
+/// Use it for values that don't fit in the 32-bit GraphQL `Int`, e.g. `BigInt<u64>`.
+/// `i128` and `u128` are always resolved as `BigInt`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigInt<T>(pub T);
+
+struct BigIntScalar;
+
+impl Register for BigIntScalar {
+    fn register(registry: Registry) -> Registry {
+        registry.register_type(dynamic::Scalar::new(BIG_INT))
+    }
+}
+
+fn parse_big_int<T>(value: Result<dynamic::ValueAccessor>) -> InputValueResult<T>
+where
+    T: FromStr + GetInputTypeRef,
+    T::Err: Display,
+{
+    let value = value?;
+    let string = match value.as_value() {
+        Value::Number(number) => number.to_string(),
+        _ => value.string()?.to_string(),
+    };
+    string.parse().map_err(InputValueError::custom)
+}
+
+impl<T> Register for BigInt<T> {
+    fn register(registry: Registry) -> Registry {
+        registry.register::<BigIntScalar>()
+    }
+}
+
+impl<T> TypeName for BigInt<T> {
+    fn get_type_name() -> Cow<'static, str> {
+        BIG_INT.into()
+    }
+}
+
+impl<T> InputTypeName for BigInt<T> {}
+
+impl<T> OutputTypeName for BigInt<T> {}
+
+impl<T> FromStr for BigInt<T>
+where
+    T: FromStr,
+{
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.parse().map(BigInt)
+    }
+}
+
+impl<T> FromValue for BigInt<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        parse_big_int(value)
+    }
+}
+
+impl<'a, T: Display> ResolveOwned<'a> for BigInt<T> {
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self.0.to_string())))
+    }
+}
+
+impl<'a, T: Display> ResolveRef<'a> for BigInt<T> {
+    fn resolve_ref(&self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self.0.to_string())))
+    }
+}
+
+macro_rules! big_int_value {
+    ($($t:ty),*) => {
+        $(
+            impl Register for $t {
+                fn register(registry: Registry) -> Registry {
+                    registry.register::<BigIntScalar>()
+                }
+            }
+            impl TypeName for $t {
+                fn get_type_name() -> Cow<'static, str> {
+                    BIG_INT.into()
+                }
+            }
+            impl InputTypeName for $t {}
+            impl OutputTypeName for $t {}
+            impl FromValue for $t {
+                fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+                    parse_big_int(value)
+                }
+            }
+            impl<'a> ResolveOwned<'a> for $t {
+                fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    Ok(Some(FieldValue::value(self.to_string())))
+                }
+            }
+            impl<'a> ResolveRef<'a> for $t {
+                fn resolve_ref(&self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    Ok(Some(FieldValue::value(self.to_string())))
+                }
+            }
+        )*
+    };
+}
+
+big_int_value!(i128, u128);
+
+macro_rules! non_zero_value {
+    ($($t:ty => $inner:ty),*) => {
+        $(
+            impl Register for $t {}
+            impl TypeName for $t {
+                fn get_type_name() -> Cow<'static, str> {
+                    dynamic::TypeRef::INT.into()
+                }
+            }
+            impl InputTypeName for $t {}
+            impl OutputTypeName for $t {}
+            impl FromValue for $t {
+                fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+                    let value = <$inner>::from_value(value).map_err(InputValueError::propagate)?;
+                    Self::new(value).ok_or_else(|| {
+                        InputValueError::custom(format!(
+                            "Only non-zero integers are accepted for {}.",
+                            stringify!($t),
+                        ))
+                    })
+                }
+            }
+            impl<'a> ResolveOwned<'a> for $t {
+                #[inline]
+                fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    self.get().resolve_owned(ctx)
+                }
+            }
+            impl<'a> ResolveRef<'a> for $t {
+                #[inline]
+                fn resolve_ref(&self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+                    self.get().resolve_owned(ctx)
+                }
+            }
+        )*
+    };
+}
+
+non_zero_value!(
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroIsize => isize,
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroUsize => usize
+);
+
+impl Register for char {}
+
+impl TypeName for char {
+    fn get_type_name() -> Cow<'static, str> {
+        dynamic::TypeRef::STRING.into()
+    }
+}
+
+impl InputTypeName for char {}
+
+impl OutputTypeName for char {}
+
+impl FromValue for char {
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        let value = value?;
+        let mut chars = value.string()?.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(InputValueError::custom(
+                "Only strings of exactly one character are accepted for char.",
+            )),
+        }
+    }
+}
+
+impl<'a> ResolveOwned<'a> for char {
+    #[inline]
+    fn resolve_owned(self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self.to_string())))
+    }
+}
+
+impl<'a> ResolveRef<'a> for char {
+    #[inline]
+    fn resolve_ref(&self, _ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        Ok(Some(FieldValue::value(self.to_string())))
+    }
+}
+
+impl<T: Register + 'static> Register for Wrapping<T> {
+    fn register(registry: Registry) -> Registry {
+        registry.register::<T>()
+    }
+}
+
+impl<T: TypeName + 'static> TypeName for Wrapping<T> {
+    fn get_type_name() -> Cow<'static, str> {
+        <T as TypeName>::get_type_name()
+    }
+}
+
+impl<T: InputTypeName + 'static> InputTypeName for Wrapping<T> {}
+
+impl<T: OutputTypeName + 'static> OutputTypeName for Wrapping<T> {}
+
+impl<T> FromValue for Wrapping<T>
+where
+    T: FromValue + InputTypeName + 'static,
+{
+    fn from_value(value: Result<dynamic::ValueAccessor>) -> InputValueResult<Self> {
+        T::from_value(value)
+            .map(Wrapping)
+            .map_err(InputValueError::propagate)
+    }
+}
+
+impl<'a, T: ResolveOwned<'a>> ResolveOwned<'a> for Wrapping<T> {
+    #[inline]
+    fn resolve_owned(self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        self.0.resolve_owned(ctx)
+    }
+}
+
+impl<'a, T: ResolveRef<'a>> ResolveRef<'a> for Wrapping<T> {
+    #[inline]
+    fn resolve_ref(&'a self, ctx: &Context) -> Result<Option<FieldValue<'a>>> {
+        self.0.resolve_ref(ctx)
+    }
+}